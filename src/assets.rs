@@ -0,0 +1,200 @@
+use glam::Vec3;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::material::Material;
+use crate::texture::Texture;
+
+/// Errores al cargar un recurso desde disco
+#[derive(Debug)]
+pub enum AssetError {
+    /// El archivo no existe
+    MissingFile { path: PathBuf },
+    /// El archivo existe pero no se pudo leer
+    Io { path: PathBuf, source: io::Error },
+    /// El contenido no es un OBJ, MTL, PNG o TOML válido
+    Parse { path: PathBuf, message: String },
+    /// El OBJ no contiene ningún triángulo
+    EmptyMesh { path: PathBuf },
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssetError::MissingFile { path } =>
+                write!(f, "No se encontró el archivo {}", path.display()),
+            AssetError::Io { path, source } =>
+                write!(f, "No se pudo leer {}: {}", path.display(), source),
+            AssetError::Parse { path, message } =>
+                write!(f, "Error al interpretar {}: {}", path.display(), message),
+            AssetError::EmptyMesh { path } =>
+                write!(f, "La malla {} no contiene triángulos", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AssetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AssetError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<AssetError> for String {
+    fn from(error: AssetError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Malla cargada junto con sus materiales y su centro y radio envolvente en espacio del modelo
+pub struct MeshAsset {
+    /// Una submalla por objeto y material: todas sus caras comparten `mesh.material_id`
+    pub models: Vec<tobj::Model>,
+    pub materials: Vec<Material>,
+    /// Promedio de los vértices de todas las submallas
+    pub centroid: Vec3,
+    /// Distancia máxima de un vértice al centroide
+    pub radius: f32,
+    default_material: Material,
+}
+
+impl MeshAsset {
+    fn new(models: Vec<tobj::Model>, materials: Vec<Material>) -> Self {
+        let vertices = || {
+            models.iter().flat_map(|model| {
+                model.mesh.positions.chunks_exact(3).map(|p| Vec3::new(p[0], p[1], p[2]))
+            })
+        };
+
+        let (sum, count) = vertices().fold((Vec3::ZERO, 0usize), |(sum, count), v| (sum + v, count + 1));
+        let centroid = if count > 0 { sum / count as f32 } else { Vec3::ZERO };
+        let radius = vertices().map(|v| (v - centroid).length()).fold(0.0f32, f32::max);
+
+        MeshAsset { models, materials, centroid, radius, default_material: Material::default() }
+    }
+
+    /// Material de una submalla (el gris por defecto si no tiene o el índice no existe)
+    pub fn material(&self, model: &tobj::Model) -> &Material {
+        model.mesh.material_id
+            .and_then(|id| self.materials.get(id))
+            .unwrap_or(&self.default_material)
+    }
+
+    /// Escala que lleva la malla al radio indicado en unidades del mundo
+    pub fn scale_for(&self, radius: f32) -> f32 {
+        if self.radius > 0.0 { radius / self.radius } else { 1.0 }
+    }
+}
+
+/// Cache de recursos por ruta: cada archivo se lee y procesa una sola vez
+#[derive(Default)]
+pub struct AssetCache {
+    meshes: HashMap<PathBuf, Arc<MeshAsset>>,
+    textures: HashMap<PathBuf, Arc<Texture>>,
+}
+
+impl AssetCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load_mesh(&mut self, path: impl AsRef<Path>) -> Result<Arc<MeshAsset>, AssetError> {
+        let path = path.as_ref();
+        if let Some(mesh) = self.meshes.get(path) {
+            return Ok(Arc::clone(mesh));
+        }
+        let (models, obj_materials) = load_obj(path)?;
+
+        // Las texturas se buscan relativas al OBJ, igual que el .mtl
+        let directory = path.parent().unwrap_or(Path::new(""));
+        let materials = obj_materials
+            .iter()
+            .map(|material| self.convert_material(material, directory))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mesh = Arc::new(MeshAsset::new(models, materials));
+        self.meshes.insert(path.to_path_buf(), Arc::clone(&mesh));
+        Ok(mesh)
+    }
+
+    pub fn load_texture(&mut self, path: impl AsRef<Path>) -> Result<Arc<Texture>, AssetError> {
+        let path = path.as_ref();
+        if let Some(texture) = self.textures.get(path) {
+            return Ok(Arc::clone(texture));
+        }
+        let texture = Arc::new(Texture::load_png(path)?);
+        self.textures.insert(path.to_path_buf(), Arc::clone(&texture));
+        Ok(texture)
+    }
+
+    fn convert_material(&mut self, material: &tobj::Material, directory: &Path) -> Result<Material, AssetError> {
+        let diffuse_texture = if material.diffuse_texture.is_empty() {
+            None
+        } else {
+            Some(self.load_texture(directory.join(&material.diffuse_texture))?)
+        };
+        Ok(Material {
+            name: material.name.clone(),
+            diffuse: Vec3::from(material.diffuse),
+            specular: Vec3::from(material.specular),
+            shininess: material.shininess,
+            diffuse_texture,
+        })
+    }
+}
+
+fn load_obj(path: &Path) -> Result<(Vec<tobj::Model>, Vec<tobj::Material>), AssetError> {
+    let content = fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => AssetError::MissingFile { path: path.to_path_buf() },
+        _ => AssetError::Io { path: path.to_path_buf(), source },
+    })?;
+
+    // Blender escribe `usemtl` sin nombre para las caras sin material y tobj lo rechaza
+    let filtered: String = content
+        .lines()
+        .filter(|line| line.trim() != "usemtl")
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    let mut reader = Cursor::new(filtered.as_bytes());
+
+    let load_options = tobj::LoadOptions {
+        triangulate: true,
+        single_index: true, // Normales y UVs indexados igual que las posiciones
+        ignore_points: true,
+        ignore_lines: true,
+    };
+
+    // Los .mtl se resuelven relativos al OBJ; se recuerda la ruta para el mensaje de error
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let mtl_path = RefCell::new(None);
+    let (models, materials) = tobj::load_obj_buf(
+        &mut reader,
+        &load_options,
+        |p| {
+            let full_path = directory.join(p);
+            *mtl_path.borrow_mut() = Some(full_path.clone());
+            tobj::load_mtl(full_path)
+        }
+    ).map_err(|e| AssetError::Parse { path: path.to_path_buf(), message: e.to_string() })?;
+
+    let materials = materials.map_err(|e| {
+        let mtl_path = mtl_path.into_inner().unwrap_or_else(|| path.to_path_buf());
+        match e {
+            tobj::LoadError::OpenFileFailed => AssetError::MissingFile { path: mtl_path },
+            other => AssetError::Parse { path: mtl_path, message: other.to_string() },
+        }
+    })?;
+
+    if models.iter().all(|model| model.mesh.indices.is_empty()) {
+        return Err(AssetError::EmptyMesh { path: path.to_path_buf() });
+    }
+
+    Ok((models, materials))
+}
@@ -0,0 +1,141 @@
+/// Paso fijo de la simulación en segundos simulados. Las velocidades de los cuerpos están
+/// en unidades por segundo simulado, así que el resultado no depende de este valor.
+pub const SIM_STEP: f32 = 1.0 / 60.0;
+
+/// Fracción de paso que puede faltar para darlo por completo. En coma flotante 20 × 0.05 y
+/// 200 × 0.005 no suman exactamente lo mismo y pueden caer a ambos lados del límite de un
+/// paso; con este margen (~17 µs) ambos dan los mismos pasos y lo que falta queda como
+/// resto negativo en el acumulador, así que a la larga no se pierde ni se gana tiempo.
+const STEP_TOLERANCE: f64 = 1e-3;
+
+/// Reloj de la simulación con paso fijo. Acumula el `dt` real de cada cuadro y lo consume en
+/// pasos enteros de `SIM_STEP`: tras el mismo tiempo real se han dado los mismos pasos a
+/// 20 FPS que a 200 FPS, y lo que sobra queda en el acumulador para el cuadro siguiente.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimClock {
+    steps: i64,
+    /// Tiempo pendiente en pasos (entre -`STEP_TOLERANCE` y 1)
+    accumulator: f64,
+}
+
+impl SimClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Suma `dt` segundos al acumulador y devuelve cuántos pasos completos se avanzaron
+    pub fn advance(&mut self, dt: f32) -> i64 {
+        self.accumulator += dt as f64 / SIM_STEP as f64;
+        let steps = (self.accumulator + STEP_TOLERANCE).floor();
+        self.accumulator -= steps;
+        self.steps += steps as i64;
+        steps as i64
+    }
+
+    /// Tiempo simulado del último paso completo. Se calcula a partir del número de pasos
+    /// para no acumular error de redondeo.
+    pub fn time(&self) -> f64 {
+        self.steps as f64 * SIM_STEP as f64
+    }
+
+    /// Tiempo simulado incluyendo la fracción de paso pendiente, para interpolar el dibujo
+    /// entre dos pasos cuando se renderiza más rápido de lo que avanza la simulación
+    pub fn interpolated_time(&self) -> f64 {
+        self.time() + self.accumulator * SIM_STEP as f64
+    }
+}
+
+/// Velocidades de la simulación que se recorren con `faster` y `slower`
+const WARP_LEVELS: [f32; 13] = [0.1, 0.25, 0.5, 1.0, 2.0, 5.0, 10.0, 50.0, 100.0, 500.0, 1000.0, 5000.0, 10000.0];
+/// Índice de x1 en `WARP_LEVELS`
+const NORMAL_LEVEL: usize = 3;
+
+/// Escala del tiempo simulado respecto al real: pausa, cámara lenta, avance rápido y
+/// reversa. Solo afecta a los cuerpos y a la animación de los shaders; la cámara sigue
+/// moviéndose con el tiempo real.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeWarp {
+    level: usize,
+    paused: bool,
+    reversed: bool,
+}
+
+impl Default for TimeWarp {
+    fn default() -> Self {
+        TimeWarp { level: NORMAL_LEVEL, paused: false, reversed: false }
+    }
+}
+
+impl TimeWarp {
+    pub fn faster(&mut self) {
+        self.level = (self.level + 1).min(WARP_LEVELS.len() - 1);
+    }
+
+    pub fn slower(&mut self) {
+        self.level = self.level.saturating_sub(1);
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn toggle_reverse(&mut self) {
+        self.reversed = !self.reversed;
+    }
+
+    /// Vuelve a x1 hacia adelante y sin pausa
+    pub fn reset(&mut self) {
+        *self = TimeWarp::default();
+    }
+
+    /// Segundos simulados por segundo real (0 en pausa, negativo en reversa)
+    pub fn rate(&self) -> f32 {
+        if self.paused {
+            return 0.0;
+        }
+        let rate = WARP_LEVELS[self.level];
+        if self.reversed { -rate } else { rate }
+    }
+
+    /// Texto para el HUD, p. ej. `x100`, `x-0.5` o `PAUSA (x10)`
+    pub fn label(&self) -> String {
+        let rate = WARP_LEVELS[self.level];
+        let sign = if self.reversed { "-" } else { "" };
+        if self.paused {
+            format!("PAUSA (x{}{})", sign, rate)
+        } else {
+            format!("x{}{}", sign, rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_steps_at_any_frame_rate() {
+        let mut slow = SimClock::new();
+        let slow_steps: i64 = (0..20).map(|_| slow.advance(0.05)).sum();
+        let mut fast = SimClock::new();
+        let fast_steps: i64 = (0..200).map(|_| fast.advance(0.005)).sum();
+
+        assert_eq!(slow_steps, 60);
+        assert_eq!(slow_steps, fast_steps);
+        assert!((slow.interpolated_time() - fast.interpolated_time()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn same_steps_at_common_refresh_rates() {
+        // 10 s reales a x1 y a x0.1 en monitores de 30, 144 y 165 Hz
+        for (rate, expected) in [(1.0, 600), (0.1, 60)] {
+            for fps in [30, 144, 165] {
+                let mut clock = SimClock::new();
+                let dt = rate / fps as f32;
+                let steps: i64 = (0..10 * fps).map(|_| clock.advance(dt)).sum();
+                assert_eq!(steps, expected, "x{} a {} FPS", rate, fps);
+                assert!(clock.accumulator.abs() < STEP_TOLERANCE, "x{} a {} FPS: resto {}", rate, fps, clock.accumulator);
+            }
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::orbit::OrbitalElements;
+use crate::scene::SolarSystem;
+
+/// Día juliano de la época J2000.0 (2000-01-01 12:00 TT)
+pub const J2000: f64 = 2_451_545.0;
+/// Día juliano del 1970-01-01 00:00 UTC
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+const DAYS_PER_CENTURY: f64 = 36_525.0;
+
+/// Días simulados por segundo del modo efemérides: un año cada ~18 s, parecido al periodo
+/// de la Tierra en la escena
+pub const DEFAULT_DAYS_PER_SECOND: f64 = 20.0;
+
+/// Elementos medios en J2000 y su variación por siglo juliano. Los ángulos van en grados:
+/// longitud media (L), longitud del perihelio (ϖ = Ω + ω) y longitud del nodo ascendente (Ω).
+struct MeanElements {
+    id: &'static str,
+    eccentricity: [f64; 2],
+    inclination: [f64; 2],
+    mean_longitude: [f64; 2],
+    longitude_of_periapsis: [f64; 2],
+    longitude_of_ascending_node: [f64; 2],
+}
+
+/// Elementos de "Keplerian Elements for Approximate Positions of the Major Planets"
+/// (E. M. Standish, JPL), válidos entre 1800 y 2050 con errores de minutos de arco. `earth`
+/// es el baricentro Tierra-Luna. La Luna usa sus elementos medios geocéntricos (Meeus), que
+/// ignoran las perturbaciones del Sol y pueden desviarse un par de grados. Ceres usa sus
+/// elementos osculantes (JPL) sin precesión, con errores de un par de grados.
+const MEAN_ELEMENTS: &[MeanElements] = &[
+    MeanElements {
+        id: "mercury",
+        eccentricity: [0.205_635_93, 0.000_019_06],
+        inclination: [7.004_979_02, -0.005_947_49],
+        mean_longitude: [252.250_323_50, 149_472.674_111_75],
+        longitude_of_periapsis: [77.457_796_28, 0.160_476_89],
+        longitude_of_ascending_node: [48.330_765_93, -0.125_340_81],
+    },
+    MeanElements {
+        id: "venus",
+        eccentricity: [0.006_776_72, -0.000_041_07],
+        inclination: [3.394_676_05, -0.000_788_90],
+        mean_longitude: [181.979_099_50, 58_517.815_387_29],
+        longitude_of_periapsis: [131.602_467_18, 0.002_683_29],
+        longitude_of_ascending_node: [76.679_842_55, -0.277_694_18],
+    },
+    MeanElements {
+        id: "earth",
+        eccentricity: [0.016_711_23, -0.000_043_92],
+        inclination: [-0.000_015_31, -0.012_946_68],
+        mean_longitude: [100.464_571_66, 35_999.372_449_81],
+        longitude_of_periapsis: [102.937_681_93, 0.323_273_64],
+        longitude_of_ascending_node: [0.0, 0.0],
+    },
+    MeanElements {
+        id: "moon",
+        eccentricity: [0.054_9, 0.0],
+        inclination: [5.145, 0.0],
+        mean_longitude: [218.316_447_7, 481_267.881_234_21],
+        longitude_of_periapsis: [83.353_246_5, 4_069.013_728_7],
+        longitude_of_ascending_node: [125.044_547_9, -1_934.136_289_1],
+    },
+    MeanElements {
+        id: "mars",
+        eccentricity: [0.093_394_10, 0.000_078_82],
+        inclination: [1.849_691_42, -0.008_131_31],
+        mean_longitude: [-4.553_432_05, 19_140.302_684_99],
+        longitude_of_periapsis: [-23.943_629_59, 0.444_410_88],
+        longitude_of_ascending_node: [49.559_538_91, -0.292_573_43],
+    },
+    MeanElements {
+        id: "ceres",
+        eccentricity: [0.078_5, 0.0],
+        inclination: [10.59, 0.0],
+        mean_longitude: [161.4, 7_819.4],
+        longitude_of_periapsis: [153.9, 0.0],
+        longitude_of_ascending_node: [80.3, 0.0],
+    },
+    MeanElements {
+        id: "jupiter",
+        eccentricity: [0.048_386_24, -0.000_132_53],
+        inclination: [1.304_396_95, -0.001_837_14],
+        mean_longitude: [34.396_440_51, 3_034.746_127_75],
+        longitude_of_periapsis: [14.728_479_83, 0.212_526_68],
+        longitude_of_ascending_node: [100.473_909_09, 0.204_691_06],
+    },
+    MeanElements {
+        id: "saturn",
+        eccentricity: [0.053_861_79, -0.000_509_91],
+        inclination: [2.485_991_87, 0.001_936_09],
+        mean_longitude: [49.954_244_23, 1_222.493_622_01],
+        longitude_of_periapsis: [92.598_878_31, -0.418_972_16],
+        longitude_of_ascending_node: [113.662_424_48, -0.288_677_94],
+    },
+    MeanElements {
+        id: "uranus",
+        eccentricity: [0.047_257_44, -0.000_043_97],
+        inclination: [0.772_637_83, -0.002_429_39],
+        mean_longitude: [313.238_104_51, 428.482_027_85],
+        longitude_of_periapsis: [170.954_276_30, 0.408_052_81],
+        longitude_of_ascending_node: [74.016_925_03, 0.042_405_89],
+    },
+    MeanElements {
+        id: "neptune",
+        eccentricity: [0.008_590_48, 0.000_051_05],
+        inclination: [1.770_043_47, 0.000_353_72],
+        mean_longitude: [-55.120_029_69, 218.459_453_25],
+        longitude_of_periapsis: [44.964_762_27, -0.322_414_64],
+        longitude_of_ascending_node: [131.784_225_74, -0.005_086_64],
+    },
+    MeanElements {
+        id: "pluto",
+        eccentricity: [0.248_827_30, 0.000_051_70],
+        inclination: [17.140_012_06, 0.000_048_18],
+        mean_longitude: [238.929_038_33, 145.207_805_15],
+        longitude_of_periapsis: [224.068_916_29, -0.040_629_42],
+        longitude_of_ascending_node: [110.303_936_84, -0.011_834_82],
+    },
+];
+
+/// Nombres aceptados en la clave `ephemeris` de los cuerpos
+pub fn is_known(id: &str) -> bool {
+    MEAN_ELEMENTS.iter().any(|elements| elements.id == id)
+}
+
+/// Modo efemérides: el tiempo simulado 0 corresponde a `epoch` y los cuerpos con clave
+/// `ephemeris` se colocan en su posición heliocéntrica (o geocéntrica, la Luna) real.
+#[derive(Debug, Clone, Copy)]
+pub struct Ephemeris {
+    /// Día juliano en t = 0
+    pub epoch: f64,
+    pub days_per_second: f64,
+}
+
+impl Ephemeris {
+    pub fn new(epoch: f64) -> Self {
+        Ephemeris { epoch, days_per_second: DEFAULT_DAYS_PER_SECOND }
+    }
+
+    /// Interpreta `AAAA-MM-DD`, `AAAA-MM-DDTHH:MM` (UTC) o `hoy`
+    pub fn parse(text: &str) -> Result<Self, String> {
+        if text == "hoy" {
+            return Ok(Ephemeris::new(now()));
+        }
+        parse_date(text).map(Ephemeris::new)
+    }
+
+    /// Día juliano en el tiempo simulado `t`
+    pub fn date_at(&self, t: f64) -> f64 {
+        self.epoch + t * self.days_per_second
+    }
+
+    /// Sustituye la orientación, la excentricidad, la fase y el periodo de las órbitas por
+    /// los reales en la fecha de la época. El semieje mayor de la escena se conserva: las
+    /// distancias siguen comprimidas para que todo el sistema quepa en pantalla.
+    ///
+    /// La eclíptica de la escena está reflejada respecto a la real (su eje y va a +Z en el
+    /// mundo), así que los elementos se reflejan también: la inclinación pasa a 180° − i y
+    /// el nodo a −Ω. El argumento del periapsis y la anomalía media no cambian, y cada cuerpo
+    /// acaba en su posición real con el norte de la eclíptica hacia +Y.
+    pub fn apply(&self, system: &mut SolarSystem) {
+        let centuries = (self.epoch - J2000) / DAYS_PER_CENTURY;
+        let at = |[value, rate]: [f64; 2]| value + rate * centuries;
+
+        for body in system.bodies.iter_mut() {
+            let Some(id) = &body.ephemeris else { continue };
+            let Some(elements) = MEAN_ELEMENTS.iter().find(|elements| elements.id == id) else { continue };
+            let Some(orbit) = &mut body.orbit else { continue };
+
+            let node = at(elements.longitude_of_ascending_node);
+            let periapsis = at(elements.longitude_of_periapsis);
+            let period_days = 360.0 / elements.mean_longitude[1] * DAYS_PER_CENTURY;
+            *orbit = OrbitalElements {
+                semi_major_axis: orbit.semi_major_axis,
+                eccentricity: at(elements.eccentricity) as f32,
+                inclination: (180.0 - at(elements.inclination)) as f32,
+                longitude_of_ascending_node: (-node).rem_euclid(360.0) as f32,
+                argument_of_periapsis: (periapsis - node).rem_euclid(360.0) as f32,
+                mean_anomaly: (at(elements.mean_longitude) - periapsis).rem_euclid(360.0) as f32,
+                period: (period_days / self.days_per_second) as f32,
+            };
+        }
+    }
+}
+
+/// Día juliano actual según el reloj del sistema
+pub fn now() -> f64 {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs_f64()).unwrap_or(0.0);
+    UNIX_EPOCH_JD + seconds / 86_400.0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Día juliano de una fecha del calendario gregoriano (Meeus, cap. 7)
+pub fn julian_day(year: i32, month: u32, day: f64) -> f64 {
+    let (y, m) = if month <= 2 { (year - 1, month + 12) } else { (year, month) };
+    let a = (y as f64 / 100.0).floor();
+    let b = 2.0 - a + (a / 4.0).floor();
+    (365.25 * (y as f64 + 4716.0)).floor() + (30.6001 * (m as f64 + 1.0)).floor() + day + b - 1524.5
+}
+
+/// Interpreta `AAAA-MM-DD` con hora opcional `THH:MM` y devuelve su día juliano
+pub fn parse_date(text: &str) -> Result<f64, String> {
+    let invalid = || format!("Fecha inválida '{}' (se espera AAAA-MM-DD o AAAA-MM-DDTHH:MM)", text);
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (text, None),
+    };
+
+    let mut parts = date.splitn(3, '-');
+    let mut next = || parts.next().ok_or_else(invalid);
+    let year: i32 = next()?.parse().map_err(|_| invalid())?;
+    let month: u32 = next()?.parse().map_err(|_| invalid())?;
+    let day: u32 = next()?.parse().map_err(|_| invalid())?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(invalid());
+    }
+
+    let hours = match time {
+        Some(time) => {
+            let (h, m) = time.split_once(':').ok_or_else(invalid)?;
+            let h: u32 = h.parse().map_err(|_| invalid())?;
+            let m: u32 = m.parse().map_err(|_| invalid())?;
+            if h > 23 || m > 59 {
+                return Err(invalid());
+            }
+            h as f64 + m as f64 / 60.0
+        },
+        None => 0.0,
+    };
+    Ok(julian_day(year, month, day as f64 + hours / 24.0))
+}
+
+/// Fecha gregoriana `AAAA-MM-DD HH:MM` de un día juliano (Meeus, cap. 7)
+pub fn format_date(jd: f64) -> String {
+    // Redondear al minuto antes de separar día y hora para no mostrar 24:00
+    let total_minutes = ((jd + 0.5) * 1440.0).round() as i64;
+    let z = total_minutes.div_euclid(1440) as f64;
+    let minutes = total_minutes.rem_euclid(1440);
+    let alpha = ((z - 1_867_216.25) / 36_524.25).floor();
+    let a = z + 1.0 + alpha - (alpha / 4.0).floor();
+    let b = a + 1524.0;
+    let c = ((b - 122.1) / 365.25).floor();
+    let d = (365.25 * c).floor();
+    let e = ((b - d) / 30.6001).floor();
+
+    let day = b - d - (30.6001 * e).floor();
+    let month = if e < 14.0 { e - 1.0 } else { e - 13.0 };
+    let year = if month > 2.0 { c - 4716.0 } else { c - 4715.0 };
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year as i32, month as u32, day as u32, minutes / 60, minutes % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn j2000_julian_day() {
+        assert_eq!(parse_date("2000-01-01T12:00"), Ok(J2000));
+    }
+
+    #[test]
+    fn format_round_trip() {
+        for text in ["2000-01-01 12:00", "2020-10-13 06:45", "1969-07-20 20:17", "2024-02-29 23:59"] {
+            assert_eq!(format_date(parse_date(text).unwrap()), text);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        for text in ["2021-13-01", "2021-00-10", "2021-02-30", "2021-02-29", "2021-04-31", "2021-01-00"] {
+            assert!(parse_date(text).is_err(), "{} debería ser inválida", text);
+        }
+    }
+
+    /// Longitud eclíptica heliocéntrica (grados) del cuerpo `id` de la escena en `date`
+    fn heliocentric_longitude(id: &str, date: &str) -> f32 {
+        let mut system = SolarSystem::load("config/scene.toml").unwrap();
+        Ephemeris::new(parse_date(date).unwrap()).apply(&mut system);
+        let body = system.bodies.iter().find(|body| body.ephemeris.as_deref() == Some(id)).unwrap();
+        // La eclíptica real (x, y, z) está en el mundo en (x, z, -y)
+        let position = body.relative_position(0.0);
+        (-position.z).atan2(position.x).to_degrees().rem_euclid(360.0)
+    }
+
+    #[test]
+    fn earth_longitude_at_equinox_and_solstice() {
+        // En el equinoccio de marzo el Sol está en longitud eclíptica 0° vista desde la
+        // Tierra, así que la Tierra está en 180° vista desde el Sol; en el solsticio de
+        // junio, en 270°
+        for (date, expected) in [("2020-03-20T03:50", 180.0), ("2020-06-20T21:44", 270.0)] {
+            let longitude = heliocentric_longitude("earth", date);
+            assert!((longitude - expected).abs() < 1.0, "{}: longitud {}", date, longitude);
+        }
+    }
+
+    #[test]
+    fn ceres_at_opposition() {
+        // En oposición Ceres y la Tierra tienen la misma longitud heliocéntrica
+        for date in ["2020-08-28", "2023-03-21"] {
+            let ceres = heliocentric_longitude("ceres", date);
+            let earth = heliocentric_longitude("earth", date);
+            let difference = (ceres - earth + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(difference.abs() < 2.0, "{}: Ceres {} y la Tierra {}", date, ceres, earth);
+        }
+    }
+}
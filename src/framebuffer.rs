@@ -1,3 +1,110 @@
+use sdl2::pixels::Color;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Buffer de color en CPU (RGB24) donde escribe el rasterizador
+pub struct Framebuffer {
+    buffer: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl Framebuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Framebuffer {
+            buffer: vec![0; width * height * 3],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Bytes RGB fila por fila (pitch = width * 3)
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    pub fn clear(&mut self, color: Color) {
+        for pixel in self.buffer.chunks_exact_mut(3) {
+            pixel[0] = color.r;
+            pixel[1] = color.g;
+            pixel[2] = color.b;
+        }
+    }
+
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: Color) {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return;
+        }
+        let idx = ((y as usize) * self.width + (x as usize)) * 3;
+        self.buffer[idx] = color.r;
+        self.buffer[idx + 1] = color.g;
+        self.buffer[idx + 2] = color.b;
+    }
+
+    /// Línea con el algoritmo de Bresenham (los píxeles fuera de pantalla se descartan)
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: Color) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (x0, y0);
+
+        loop {
+            self.set_pixel(x, y, color);
+            if x == x1 && y == y1 { break; }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Guarda la imagen según la extensión del archivo (.png o .ppm)
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()) {
+            Some(ext) if ext == "png" => self.save_png(path),
+            Some(ext) if ext == "ppm" => self.save_ppm(path),
+            _ => Err(format!("Formato de imagen no soportado: {} (usa .png o .ppm)", path.display())),
+        }
+    }
+
+    /// PPM binario (P6)
+    pub fn save_ppm(&self, path: &Path) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| format!("No se pudo crear {}: {}", path.display(), e))?;
+        let mut writer = BufWriter::new(file);
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)
+            .and_then(|_| writer.write_all(&self.buffer))
+            .and_then(|_| writer.flush())
+            .map_err(|e| format!("No se pudo escribir {}: {}", path.display(), e))
+    }
+
+    pub fn save_png(&self, path: &Path) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| format!("No se pudo crear {}: {}", path.display(), e))?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.buffer))
+            .map_err(|e| format!("No se pudo escribir {}: {}", path.display(), e))
+    }
+}
+
 pub struct ZBuffer {
     buffer: Vec<f32>,
     width: usize,
@@ -17,16 +124,88 @@ impl ZBuffer {
         self.buffer.fill(f32::INFINITY);
     }
     
-    pub fn test_and_set(&mut self, x: i32, y: i32, depth: f32) -> bool {
-        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
-            return false;
+}
+
+/// Franja de filas [y_start, y_end) con acceso exclusivo a su parte del color y la
+/// profundidad, para que varios hilos rasterizen a la vez sin sincronización
+pub struct Tile<'a> {
+    color: &'a mut [u8],
+    depth: &'a mut [f32],
+    width: usize,
+    y_start: usize,
+    y_end: usize,
+}
+
+impl<'a> Tile<'a> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn y_start(&self) -> usize {
+        self.y_start
+    }
+
+    pub fn y_end(&self) -> usize {
+        self.y_end
+    }
+
+    /// Índice local del píxel (x, y) en coordenadas de pantalla, si pertenece a la franja
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < self.y_start as i32 || x >= self.width as i32 || y >= self.y_end as i32 {
+            return None;
+        }
+        Some((y as usize - self.y_start) * self.width + x as usize)
+    }
+
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: Color) {
+        if let Some(idx) = self.index(x, y) {
+            self.color[idx * 3] = color.r;
+            self.color[idx * 3 + 1] = color.g;
+            self.color[idx * 3 + 2] = color.b;
         }
-        let idx = (y as usize) * self.width + (x as usize);
-        if depth < self.buffer[idx] {
-            self.buffer[idx] = depth;
-            true
-        } else {
-            false
+    }
+
+    /// Mezcla `color` sobre el píxel según su alfa
+    pub fn blend_pixel(&mut self, x: i32, y: i32, color: Color) {
+        if let Some(idx) = self.index(x, y) {
+            let alpha = color.a as u32;
+            for (channel, value) in [color.r, color.g, color.b].into_iter().enumerate() {
+                let dst = &mut self.color[idx * 3 + channel];
+                *dst = ((value as u32 * alpha + *dst as u32 * (255 - alpha) + 127) / 255) as u8;
+            }
         }
     }
+
+    /// Prueba de profundidad sin escribir, para la geometría transparente
+    pub fn test(&self, x: i32, y: i32, depth: f32) -> bool {
+        self.index(x, y).is_some_and(|idx| depth < self.depth[idx])
+    }
+
+    pub fn test_and_set(&mut self, x: i32, y: i32, depth: f32) -> bool {
+        match self.index(x, y) {
+            Some(idx) if depth < self.depth[idx] => {
+                self.depth[idx] = depth;
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Divide color y profundidad en franjas de `rows` filas (la última puede ser más corta)
+pub fn split_tiles<'a>(framebuffer: &'a mut Framebuffer, zbuffer: &'a mut ZBuffer, rows: usize) -> Vec<Tile<'a>> {
+    debug_assert_eq!((zbuffer.width, zbuffer.height), (framebuffer.width(), framebuffer.height()));
+    let width = framebuffer.width;
+    framebuffer.buffer
+        .chunks_mut(width * 3 * rows)
+        .zip(zbuffer.buffer.chunks_mut(width * rows))
+        .enumerate()
+        .map(|(i, (color, depth))| Tile {
+            width,
+            y_start: i * rows,
+            y_end: i * rows + depth.len() / width,
+            color,
+            depth,
+        })
+        .collect()
 }
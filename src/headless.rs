@@ -0,0 +1,163 @@
+use glam::Vec3;
+use std::path::PathBuf;
+use crate::assets::AssetCache;
+use crate::clock::SIM_STEP;
+use crate::ephemeris::{Ephemeris, format_date};
+use crate::lod::SphereLod;
+use crate::nbody::NBody;
+use crate::presets::{SHADER_PRESETS_PATH, ShaderPresets};
+use crate::renderer::{DEFAULT_WIDTH, DEFAULT_HEIGHT, RenderContext};
+use crate::scene::{SCENE_PATH, SolarSystem, generate_stars, render_scene};
+
+pub const USAGE: &str = "Uso: sistema-solar --render-frame <salida.png|salida.ppm> \
+[--time <segundos>] [--camera x,y,z] [--target x,y,z] [--size ANCHOxALTO] [--scene escena.toml] [--shaders presets.toml] [--date AAAA-MM-DD|hoy] [--nbody] [--single-thread]";
+
+/// Parámetros de un render de un solo cuadro sin ventana
+#[derive(Debug, Clone)]
+pub struct FrameOptions {
+    pub output: PathBuf,
+    pub time: f64,
+    pub camera_position: Vec3,
+    pub camera_target: Vec3,
+    pub width: u32,
+    pub height: u32,
+    /// Archivo TOML con los cuerpos de la escena
+    pub scene: PathBuf,
+    /// Archivo TOML con los presets de los shaders
+    pub shader_presets: PathBuf,
+    /// Fecha del modo efemérides en t = 0; sin ella se usan las órbitas de la escena
+    pub date: Option<Ephemeris>,
+    /// Integrar con gravedad N-cuerpos desde t = 0 hasta `time`
+    pub nbody: bool,
+    /// Rasterizar en un solo hilo (útil para comparar con el camino en paralelo)
+    pub single_thread: bool,
+}
+
+impl Default for FrameOptions {
+    fn default() -> Self {
+        FrameOptions {
+            output: PathBuf::from("frame.png"),
+            time: 0.0,
+            camera_position: Vec3::new(0.0, 60.0, 400.0),
+            camera_target: Vec3::ZERO,
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            scene: PathBuf::from(SCENE_PATH),
+            shader_presets: PathBuf::from(SHADER_PRESETS_PATH),
+            date: None,
+            nbody: false,
+            single_thread: false,
+        }
+    }
+}
+
+fn parse_vec3(value: &str) -> Result<Vec3, String> {
+    let parts: Vec<f32> = value
+        .split(',')
+        .map(|p| p.trim().parse::<f32>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| format!("Vector inválido '{}' (se espera x,y,z)", value))?;
+    match parts.as_slice() {
+        [x, y, z] => Ok(Vec3::new(*x, *y, *z)),
+        _ => Err(format!("Vector inválido '{}' (se espera x,y,z)", value)),
+    }
+}
+
+fn parse_size(value: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("Resolución inválida '{}' (se espera ANCHOxALTO)", value);
+    let (w, h) = value.split_once(['x', 'X']).ok_or_else(invalid)?;
+    let width = w.trim().parse::<u32>().map_err(|_| invalid())?;
+    let height = h.trim().parse::<u32>().map_err(|_| invalid())?;
+    if width == 0 || height == 0 {
+        return Err(invalid());
+    }
+    Ok((width, height))
+}
+
+/// Interpreta los argumentos de línea de comandos (sin el nombre del programa)
+pub fn parse_args(args: &[String]) -> Result<FrameOptions, String> {
+    let mut options = FrameOptions::default();
+    let mut iter = args.iter();
+
+    while let Some(flag) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("Falta el valor de {}\n{}", flag, USAGE));
+        match flag.as_str() {
+            "--render-frame" => options.output = PathBuf::from(value()?),
+            "--time" => {
+                let v = value()?;
+                options.time = v.parse().map_err(|_| format!("Tiempo inválido '{}'", v))?;
+            },
+            "--camera" => options.camera_position = parse_vec3(value()?)?,
+            "--target" => options.camera_target = parse_vec3(value()?)?,
+            "--size" => {
+                let (width, height) = parse_size(value()?)?;
+                options.width = width;
+                options.height = height;
+            },
+            "--scene" => options.scene = PathBuf::from(value()?),
+            "--shaders" => options.shader_presets = PathBuf::from(value()?),
+            "--date" => options.date = Some(Ephemeris::parse(value()?)?),
+            "--nbody" => options.nbody = true,
+            "--single-thread" => options.single_thread = true,
+            other => return Err(format!("Argumento desconocido '{}'\n{}", other, USAGE)),
+        }
+    }
+
+    match options.output.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()) {
+        Some(ext) if ext == "png" || ext == "ppm" => Ok(options),
+        _ => Err(format!("Formato de imagen no soportado: {} (usa .png o .ppm)", options.output.display())),
+    }
+}
+
+/// Renderiza un cuadro de la simulación en el tiempo indicado y lo guarda en disco
+pub fn render_frame(options: &FrameOptions) -> Result<(), String> {
+    let mut system = SolarSystem::load(&options.scene)?;
+    let sphere_lod = SphereLod::new(system.max_r);
+    let shaders = ShaderPresets::load(&options.shader_presets, &mut AssetCache::new())?;
+    system.check_shaders(&shaders)?;
+    if let Some(ephemeris) = &options.date {
+        ephemeris.apply(&mut system);
+    }
+    // En el modo N-cuerpos se integra en pasos fijos desde t = 0, igual que en la ventana
+    let mut nbody = None;
+    let snapshot = if options.nbody {
+        let integrator = nbody.insert(NBody::from_system(&system, 0.0));
+        let steps = (options.time / SIM_STEP as f64).floor() as i64;
+        integrator.integrate(steps);
+        let lead = (options.time - steps as f64 * SIM_STEP as f64) as f32;
+        system.snapshot_with(options.time, &integrator.positions(lead))
+    } else {
+        system.snapshot(options.time)
+    };
+    let stars = generate_stars();
+
+    let mut context = RenderContext::new(options.width, options.height);
+    context.parallel = !options.single_thread;
+    context.set_camera(options.camera_position, options.camera_target);
+
+    render_scene(
+        &mut context,
+        &sphere_lod,
+        &shaders,
+        &system,
+        &snapshot,
+        &stars,
+        options.time,
+    )
+    .render(&mut context);
+
+    context.framebuffer.save(&options.output)?;
+    let stats = context.stats;
+    println!("Cuadro guardado en {}", options.output.display());
+    if let Some(ephemeris) = &options.date {
+        println!("Fecha: {} UTC", format_date(ephemeris.date_at(options.time)));
+    }
+    if let Some(nbody) = &nbody {
+        println!("Deriva de energía N-cuerpos: {:+.2e}", nbody.energy_drift());
+    }
+    println!(
+        "Objetos: {} dibujados, {} descartados | Triángulos: {} dibujados, {} descartados",
+        stats.objects_drawn, stats.objects_culled, stats.triangles_drawn, stats.triangles_culled
+    );
+    Ok(())
+}
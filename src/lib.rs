@@ -0,0 +1,25 @@
+//! Motor del sistema solar: rasterizador por software, escena y shaders de los cuerpos.
+//! El binario `sistema-solar` lo usa directamente; otros crates pueden añadir sus propios
+//! shaders implementando `shaders::Shader` y dibujarlos con `renderer::render`.
+
+pub mod framebuffer;
+pub mod utils;
+pub mod shaders;
+pub mod presets;
+pub mod renderer;
+pub mod presenter;
+pub mod scene;
+pub mod clock;
+pub mod nbody;
+pub mod scene_graph;
+pub mod orbit;
+pub mod rings;
+pub mod ephemeris;
+pub mod headless;
+pub mod assets;
+pub mod texture;
+pub mod material;
+pub mod hud;
+pub mod mesh;
+pub mod lod;
+pub mod watch;
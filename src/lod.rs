@@ -0,0 +1,50 @@
+use crate::mesh::{SphereDetail, sphere};
+
+/// Una resolución de la esfera y el radio mínimo en pantalla (píxeles) a partir del cual se usa
+pub struct LodLevel {
+    pub min_screen_radius: f32,
+    pub models: Vec<tobj::Model>,
+}
+
+/// Niveles de detalle de la esfera compartida por todos los cuerpos, del más fino al más burdo
+pub struct SphereLod {
+    levels: Vec<LodLevel>,
+}
+
+/// (resolución, radio mínimo en píxeles). Con ~0.8 segmentos por píxel de radio las aristas
+/// quedan de unos 8 píxeles en pantalla; los niveles lejanos usan icosfera, que reparte
+/// mejor unos pocos triángulos.
+const LEVELS: [(SphereDetail, f32); 6] = [
+    (SphereDetail::Uv { rings: 96, segments: 192 }, 200.0),
+    (SphereDetail::Uv { rings: 48, segments: 96 }, 80.0),
+    (SphereDetail::Uv { rings: 24, segments: 48 }, 30.0),
+    (SphereDetail::Uv { rings: 12, segments: 24 }, 12.0),
+    (SphereDetail::Ico { subdivisions: 2 }, 4.0),
+    (SphereDetail::Ico { subdivisions: 1 }, 0.0),
+];
+
+impl SphereLod {
+    /// Genera todas las resoluciones con el mismo radio en espacio del modelo
+    pub fn new(radius: f32) -> Self {
+        let levels = LEVELS
+            .iter()
+            .enumerate()
+            .map(|(i, &(detail, min_screen_radius))| LodLevel {
+                min_screen_radius,
+                models: vec![tobj::Model::new(sphere(detail, radius), format!("sphere_lod_{}", i))],
+            })
+            .collect();
+        SphereLod { levels }
+    }
+
+    /// Malla para un cuerpo que ocupa `screen_radius` píxeles de radio en pantalla
+    pub fn select(&self, screen_radius: f32) -> &[tobj::Model] {
+        let level = self
+            .levels
+            .iter()
+            .find(|level| screen_radius >= level.min_screen_radius)
+            .or(self.levels.last())
+            .expect("SphereLod sin niveles");
+        &level.models
+    }
+}
@@ -0,0 +1,56 @@
+use glam::Vec3;
+use sdl2::pixels::Color;
+use std::sync::Arc;
+use crate::shaders::{Fragment, Shader};
+use crate::texture::Texture;
+
+/// Fracción de la difusa que se ve aunque la cara no reciba luz directa
+const AMBIENT: f32 = 0.25;
+
+/// Material de superficie leído de un .mtl
+#[derive(Clone)]
+pub struct Material {
+    pub name: String,
+    pub diffuse: Vec3,                          // Kd
+    pub specular: Vec3,                         // Ks
+    pub shininess: f32,                         // Ns
+    pub diffuse_texture: Option<Arc<Texture>>,  // map_Kd
+}
+
+impl Default for Material {
+    /// Gris neutro, el mismo que asigna Blender a las caras sin material
+    fn default() -> Self {
+        Material {
+            name: String::from("default"),
+            diffuse: Vec3::splat(0.8),
+            specular: Vec3::splat(0.5),
+            shininess: 250.0,
+            diffuse_texture: None,
+        }
+    }
+}
+
+/// Blinn-Phong con la difusa modulada por la textura (con el mipmap que indican las
+/// derivadas de las UV)
+impl Shader for Material {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let derivatives = fragment.uv_derivatives;
+        let albedo = match &self.diffuse_texture {
+            Some(texture) => self.diffuse * texture.sample_grad(fragment.uv, derivatives.dx, derivatives.dy),
+            None => self.diffuse,
+        };
+
+        let normal = fragment.world_normal;
+        let n_dot_l = normal.dot(fragment.light_dir).max(0.0);
+        let specular = if n_dot_l > 0.0 {
+            let half = (fragment.light_dir + fragment.view_dir).normalize_or_zero();
+            normal.dot(half).max(0.0).powf(self.shininess.max(1.0))
+        } else {
+            0.0
+        };
+
+        let color = albedo * (AMBIENT + (1.0 - AMBIENT) * n_dot_l) + self.specular * specular;
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0) as u8;
+        Color::RGB(channel(color.x), channel(color.y), channel(color.z))
+    }
+}
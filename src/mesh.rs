@@ -0,0 +1,180 @@
+use glam::{Vec2, Vec3};
+use std::collections::HashMap;
+use std::f32::consts::{PI, TAU};
+
+/// Resolución de una esfera procedural
+#[derive(Debug, Clone, Copy)]
+pub enum SphereDetail {
+    /// Anillos de latitud y segmentos de longitud
+    Uv { rings: u32, segments: u32 },
+    /// Icosaedro subdividido: cada nivel multiplica los triángulos por 4 (20, 80, 320, ...)
+    Ico { subdivisions: u32 },
+}
+
+/// Genera una esfera centrada en el origen con normales suaves y UV, con los mismos
+/// datos que entrega `tobj` (posiciones, normales, UV e índices de un solo índice)
+pub fn sphere(detail: SphereDetail, radius: f32) -> tobj::Mesh {
+    match detail {
+        SphereDetail::Uv { rings, segments } => uv_sphere(rings, segments, radius),
+        SphereDetail::Ico { subdivisions } => icosphere(subdivisions, radius),
+    }
+}
+
+/// Coordenadas de textura equirectangulares de una dirección: u crece con
+/// atan2(z, x) y v vale 1 en el polo norte (+Y)
+fn spherical_uv(dir: Vec3) -> Vec2 {
+    Vec2::new(
+        (dir.z.atan2(dir.x) / TAU).rem_euclid(1.0),
+        0.5 + dir.y.clamp(-1.0, 1.0).asin() / PI,
+    )
+}
+
+fn push_vertex(mesh: &mut tobj::Mesh, dir: Vec3, uv: Vec2, radius: f32) {
+    let position = dir * radius;
+    mesh.positions.extend_from_slice(&[position.x, position.y, position.z]);
+    mesh.normals.extend_from_slice(&[dir.x, dir.y, dir.z]);
+    mesh.texcoords.extend_from_slice(&[uv.x, uv.y]);
+}
+
+/// Esfera por anillos y segmentos. Las columnas de la costura (u = 0 y u = 1) se
+/// duplican para que la textura no se repita hacia atrás en el último segmento.
+pub fn uv_sphere(rings: u32, segments: u32, radius: f32) -> tobj::Mesh {
+    let rings = rings.max(2);
+    let segments = segments.max(3);
+    let mut mesh = tobj::Mesh::default();
+
+    for i in 0..=rings {
+        let theta = PI * i as f32 / rings as f32;
+        for j in 0..=segments {
+            let phi = TAU * j as f32 / segments as f32;
+            let dir = Vec3::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin());
+            let uv = Vec2::new(j as f32 / segments as f32, 1.0 - i as f32 / rings as f32);
+            push_vertex(&mut mesh, dir, uv, radius);
+        }
+    }
+
+    // Triángulos antihorarios vistos desde fuera
+    let stride = segments + 1;
+    for i in 0..rings {
+        for j in 0..segments {
+            let a = i * stride + j;
+            let b = a + stride;
+            let c = a + 1;
+            let d = b + 1;
+            // En los polos uno de los dos triángulos del cuadrilátero es degenerado
+            if i != 0 {
+                mesh.indices.extend_from_slice(&[a, c, b]);
+            }
+            if i != rings - 1 {
+                mesh.indices.extend_from_slice(&[c, d, b]);
+            }
+        }
+    }
+
+    mesh
+}
+
+/// Icosfera: triángulos de tamaño casi uniforme, sin la acumulación de los polos
+pub fn icosphere(subdivisions: u32, radius: f32) -> tobj::Mesh {
+    let t = (1.0 + 5.0f32.sqrt()) / 2.0;
+    let mut vertices: Vec<Vec3> = [
+        (-1.0, t, 0.0), (1.0, t, 0.0), (-1.0, -t, 0.0), (1.0, -t, 0.0),
+        (0.0, -1.0, t), (0.0, 1.0, t), (0.0, -1.0, -t), (0.0, 1.0, -t),
+        (t, 0.0, -1.0), (t, 0.0, 1.0), (-t, 0.0, -1.0), (-t, 0.0, 1.0),
+    ]
+    .iter()
+    .map(|&(x, y, z)| Vec3::new(x, y, z).normalize())
+    .collect();
+
+    let mut faces: Vec<[u32; 3]> = vec![
+        [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11],
+        [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8],
+        [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9],
+        [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1],
+    ];
+
+    // Cada subdivisión parte cada triángulo en 4 usando los puntos medios de sus aristas,
+    // compartidos entre los dos triángulos vecinos
+    for _ in 0..subdivisions {
+        let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+        let mut midpoint = |a: u32, b: u32| {
+            *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                vertices.push(((vertices[a as usize] + vertices[b as usize]) * 0.5).normalize());
+                vertices.len() as u32 - 1
+            })
+        };
+        faces = faces
+            .iter()
+            .flat_map(|&[a, b, c]| {
+                let ab = midpoint(a, b);
+                let bc = midpoint(b, c);
+                let ca = midpoint(c, a);
+                [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+            })
+            .collect();
+    }
+
+    // Los triángulos que cruzan la costura de u se desplazan a u > 1 y los vértices del
+    // polo toman la u media de su triángulo; esas esquinas se duplican con su propia UV
+    let mut mesh = tobj::Mesh::default();
+    let mut emitted: HashMap<(u32, u32, u32), u32> = HashMap::new();
+    for face in &faces {
+        let dirs = face.map(|i| vertices[i as usize]);
+        let mut uvs = dirs.map(spherical_uv);
+        let is_pole = dirs.map(|d| d.y.abs() > 0.9999);
+
+        let us: Vec<f32> = (0..3).filter(|&k| !is_pole[k]).map(|k| uvs[k].x).collect();
+        let span = us.iter().cloned().fold(f32::NEG_INFINITY, f32::max)
+            - us.iter().cloned().fold(f32::INFINITY, f32::min);
+        if span > 0.5 {
+            for uv in uvs.iter_mut() {
+                if uv.x < 0.5 { uv.x += 1.0; }
+            }
+        }
+        for k in 0..3 {
+            if is_pole[k] {
+                let others: Vec<f32> = (0..3).filter(|&o| o != k).map(|o| uvs[o].x).collect();
+                uvs[k].x = others.iter().sum::<f32>() / others.len() as f32;
+            }
+        }
+
+        for k in 0..3 {
+            let key = (face[k], uvs[k].x.to_bits(), uvs[k].y.to_bits());
+            let index = *emitted.entry(key).or_insert_with(|| {
+                push_vertex(&mut mesh, dirs[k], uvs[k], radius);
+                (mesh.positions.len() / 3 - 1) as u32
+            });
+            mesh.indices.push(index);
+        }
+    }
+
+    mesh
+}
+
+/// Corona circular plana en el plano XZ entre los radios `inner` y `outer`, con la normal
+/// hacia +Y. La u recorre el ángulo y la v va de 0 en el borde interior a 1 en el exterior.
+pub fn annulus(inner: f32, outer: f32, segments: u32) -> tobj::Mesh {
+    let segments = segments.max(3);
+    let mut mesh = tobj::Mesh::default();
+
+    for j in 0..=segments {
+        let u = j as f32 / segments as f32;
+        let (sin, cos) = (TAU * u).sin_cos();
+        for (radius, v) in [(inner, 0.0), (outer, 1.0)] {
+            mesh.positions.extend_from_slice(&[radius * cos, 0.0, radius * sin]);
+            mesh.normals.extend_from_slice(&[0.0, 1.0, 0.0]);
+            mesh.texcoords.extend_from_slice(&[u, v]);
+        }
+    }
+
+    // Triángulos antihorarios vistos desde +Y
+    for j in 0..segments {
+        let a = 2 * j;
+        let b = a + 1;
+        let c = a + 2;
+        let d = a + 3;
+        mesh.indices.extend_from_slice(&[a, c, b, b, c, d]);
+    }
+
+    mesh
+}
@@ -0,0 +1,227 @@
+use glam::{DVec3, Vec3};
+use crate::clock::SIM_STEP;
+use crate::scene::SolarSystem;
+
+/// Constante de gravitación en unidades del mundo³ / (masa solar · s²). Está elegida para
+/// que la Tierra (semieje 135, Sol de masa 1) tarde los mismos ~17.45 s por vuelta que en la
+/// escena; el resto de planetas sigue la tercera ley de Kepler con ese valor.
+pub const GRAVITATIONAL_CONSTANT: f64 = 3.19e5;
+
+/// Suavizado de la gravedad a distancias muy cortas (unidades del mundo al cuadrado) para
+/// que dos cuerpos que se cruzan no salgan despedidos con aceleraciones enormes
+const SOFTENING_SQUARED: f64 = 0.01;
+
+/// Pasos de Verlet como máximo en cada `advance`. A x10000 un cuadro de 0.05 s son 500 s
+/// simulados (30 000 pasos de `SIM_STEP`); por encima de este límite cada paso cubre varios
+/// `SIM_STEP` para que el coste por cuadro quede acotado sin frenar el tiempo.
+pub const MAX_STEPS_PER_ADVANCE: u64 = 4000;
+
+/// Modo de física alternativo: los cuerpos con masa se atraen entre sí y se integran con
+/// Verlet de velocidad (kick-drift-kick), que es simplético y reversible, así que la energía
+/// no se desvía con el tiempo y la simulación puede correr hacia atrás. Los cuerpos sin masa
+/// (como la Luna, que con las distancias comprimidas de la escena quedaría fuera de la esfera
+/// de Hill de la Tierra) siguen su órbita de Kepler alrededor de la posición integrada de su padre.
+pub struct NBody {
+    /// Índice en `SolarSystem::bodies` de cada cuerpo integrado
+    indices: Vec<usize>,
+    body_count: usize,
+    masses: Vec<f64>,
+    positions: Vec<DVec3>,
+    velocities: Vec<DVec3>,
+    accelerations: Vec<DVec3>,
+    initial_energy: f64,
+}
+
+impl NBody {
+    /// Parte de las posiciones de las órbitas de Kepler en el tiempo `t`, con la velocidad que
+    /// tendría cada cuerpo en su elipse bajo la gravedad de su padre. Al final se resta la
+    /// velocidad del centro de masas para que el sistema no derive.
+    pub fn from_system(system: &SolarSystem, t: f64) -> Self {
+        let snapshot = system.snapshot(t);
+        let count = system.bodies.len();
+
+        // Velocidad en el mundo de cada cuerpo: la de su padre más la de su órbita
+        let mut world_velocities = vec![DVec3::ZERO; count];
+        for (i, body) in system.bodies.iter().enumerate() {
+            let Some(parent) = system.parent(i) else { continue };
+            let parent_mass = system.bodies[parent].mass.unwrap_or(0.0) as f64;
+            let mu = GRAVITATIONAL_CONSTANT * (parent_mass + body.mass.unwrap_or(0.0) as f64);
+            let relative = body.orbit.map_or(Vec3::ZERO, |orbit| orbit.velocity_at(t, mu as f32));
+            world_velocities[i] = world_velocities[parent] + relative.as_dvec3();
+        }
+
+        let indices: Vec<usize> = (0..count).filter(|&i| system.bodies[i].mass.is_some()).collect();
+        let masses: Vec<f64> = indices.iter().map(|&i| system.bodies[i].mass.unwrap_or(0.0) as f64).collect();
+        let positions: Vec<DVec3> = indices.iter().map(|&i| snapshot.bodies[i].position.as_dvec3()).collect();
+        let mut velocities: Vec<DVec3> = indices.iter().map(|&i| world_velocities[i]).collect();
+
+        let total_mass: f64 = masses.iter().sum();
+        if total_mass > 0.0 {
+            let momentum: DVec3 = masses.iter().zip(&velocities).map(|(m, v)| *m * *v).sum();
+            let drift = momentum / total_mass;
+            velocities.iter_mut().for_each(|v| *v -= drift);
+        }
+
+        let mut nbody = NBody {
+            indices,
+            body_count: count,
+            masses,
+            positions,
+            velocities,
+            accelerations: Vec::new(),
+            initial_energy: 0.0,
+        };
+        nbody.accelerations = nbody.compute_accelerations();
+        nbody.initial_energy = nbody.energy();
+        nbody
+    }
+
+    /// Ningún cuerpo de la escena tiene masa
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    fn compute_accelerations(&self) -> Vec<DVec3> {
+        let mut accelerations = vec![DVec3::ZERO; self.positions.len()];
+        for i in 0..self.positions.len() {
+            for j in (i + 1)..self.positions.len() {
+                let delta = self.positions[j] - self.positions[i];
+                let distance_squared = delta.length_squared() + SOFTENING_SQUARED;
+                let force = GRAVITATIONAL_CONSTANT * delta / (distance_squared * distance_squared.sqrt());
+                accelerations[i] += force * self.masses[j];
+                accelerations[j] -= force * self.masses[i];
+            }
+        }
+        accelerations
+    }
+
+    /// Un paso de Verlet de velocidad de `dt` segundos simulados (negativo para retroceder)
+    pub fn step(&mut self, dt: f64) {
+        for (velocity, acceleration) in self.velocities.iter_mut().zip(&self.accelerations) {
+            *velocity += *acceleration * (0.5 * dt);
+        }
+        for (position, velocity) in self.positions.iter_mut().zip(&self.velocities) {
+            *position += *velocity * dt;
+        }
+        self.accelerations = self.compute_accelerations();
+        for (velocity, acceleration) in self.velocities.iter_mut().zip(&self.accelerations) {
+            *velocity += *acceleration * (0.5 * dt);
+        }
+    }
+
+    /// Avanza (o retrocede, si `steps` es negativo) el tiempo de `steps` pasos de `SIM_STEP`,
+    /// los que devuelve `SimClock::advance`, en `MAX_STEPS_PER_ADVANCE` pasos como máximo
+    pub fn advance(&mut self, steps: i64) {
+        let substeps = steps.unsigned_abs().min(MAX_STEPS_PER_ADVANCE);
+        if substeps == 0 {
+            return;
+        }
+        let dt = SIM_STEP as f64 * steps as f64 / substeps as f64;
+        for _ in 0..substeps {
+            self.step(dt);
+        }
+    }
+
+    /// Como `advance` pero siempre en pasos de `SIM_STEP`, sin límite (para el render sin
+    /// ventana, que integra de una vez desde t = 0)
+    pub fn integrate(&mut self, steps: i64) {
+        let dt = SIM_STEP as f64 * steps.signum() as f64;
+        for _ in 0..steps.unsigned_abs() {
+            self.step(dt);
+        }
+    }
+
+    /// Energía cinética más potencial del sistema
+    pub fn energy(&self) -> f64 {
+        let kinetic: f64 = self.masses.iter().zip(&self.velocities).map(|(m, v)| 0.5 * m * v.length_squared()).sum();
+        let mut potential = 0.0;
+        for i in 0..self.positions.len() {
+            for j in (i + 1)..self.positions.len() {
+                let distance_squared = (self.positions[j] - self.positions[i]).length_squared() + SOFTENING_SQUARED;
+                potential -= GRAVITATIONAL_CONSTANT * self.masses[i] * self.masses[j] / distance_squared.sqrt();
+            }
+        }
+        kinetic + potential
+    }
+
+    /// Variación relativa de la energía desde el inicio de la integración
+    pub fn energy_drift(&self) -> f64 {
+        if self.initial_energy != 0.0 {
+            (self.energy() - self.initial_energy) / self.initial_energy.abs()
+        } else {
+            0.0
+        }
+    }
+
+    /// Posiciones en el mundo por índice de `SolarSystem::bodies` para `snapshot_with`
+    /// (`None` en los cuerpos sin masa). `lead` extrapola `lead` segundos simulados desde el
+    /// último paso para dibujar suave entre pasos.
+    pub fn positions(&self, lead: f32) -> Vec<Option<Vec3>> {
+        let mut positions = vec![None; self.body_count];
+        for (k, &i) in self.indices.iter().enumerate() {
+            let position = self.positions[k] + self.velocities[k] * lead as f64;
+            positions[i] = Some(position.as_vec3());
+        }
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sol y un planeta en órbita circular a la distancia de la Tierra
+    fn two_body() -> NBody {
+        let masses = vec![1.0, 3.0e-6];
+        let radius = 135.0;
+        let speed = (GRAVITATIONAL_CONSTANT * (masses[0] + masses[1]) / radius).sqrt();
+        let mut velocities = vec![DVec3::ZERO, DVec3::new(0.0, 0.0, -speed)];
+        // Centro de masas en reposo, como en `from_system`
+        let drift = masses[1] * velocities[1] / (masses[0] + masses[1]);
+        velocities.iter_mut().for_each(|v| *v -= drift);
+
+        let mut nbody = NBody {
+            indices: vec![0, 1],
+            body_count: 2,
+            masses,
+            positions: vec![DVec3::ZERO, DVec3::new(radius, 0.0, 0.0)],
+            velocities,
+            accelerations: Vec::new(),
+            initial_energy: 0.0,
+        };
+        nbody.accelerations = nbody.compute_accelerations();
+        nbody.initial_energy = nbody.energy();
+        nbody
+    }
+
+    #[test]
+    fn energy_drift_is_bounded() {
+        // Unas 10 vueltas (~17.45 s cada una) en pasos de SIM_STEP
+        let mut nbody = two_body();
+        nbody.integrate(10_500);
+        let drift = nbody.energy_drift().abs();
+        assert!(drift < 1e-6, "deriva {:e}", drift);
+
+        // Lo mismo a x10000: un cuadro de 0.05 s con pasos más largos
+        let mut nbody = two_body();
+        nbody.advance(30_000);
+        let drift = nbody.energy_drift().abs();
+        assert!(drift < 1e-4, "deriva {:e}", drift);
+    }
+
+    #[test]
+    fn reversing_time_returns_to_start() {
+        let mut nbody = two_body();
+        let start = (nbody.positions.clone(), nbody.velocities.clone());
+        nbody.integrate(2_000);
+        nbody.integrate(-2_000);
+        nbody.advance(30_000);
+        nbody.advance(-30_000);
+        for (a, b) in nbody.positions.iter().zip(&start.0) {
+            assert!((*a - *b).length() < 1e-6, "posición {} frente a {}", a, b);
+        }
+        for (a, b) in nbody.velocities.iter().zip(&start.1) {
+            assert!((*a - *b).length() < 1e-6, "velocidad {} frente a {}", a, b);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use glam::Vec3;
+use serde::Deserialize;
+use std::f32::consts::TAU;
+
+/// Iteraciones máximas de Newton al resolver la ecuación de Kepler
+const KEPLER_ITERATIONS: usize = 12;
+/// Error aceptable en la anomalía excéntrica (radianes)
+const KEPLER_TOLERANCE: f32 = 1e-6;
+
+/// Elementos orbitales clásicos de Kepler. Los ángulos van en grados y se miden sobre el
+/// plano de la eclíptica del padre, que en el mundo es el plano XZ con el norte hacia +Y.
+/// Como en las órbitas circulares originales (x = r·cos, z = r·sin), los ángulos crecen
+/// de +X hacia +Z.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrbitalElements {
+    /// Semieje mayor en unidades del mundo
+    pub semi_major_axis: f32,
+    /// 0 = círculo; debe ser menor que 1 (órbita cerrada)
+    #[serde(default)]
+    pub eccentricity: f32,
+    /// Inclinación del plano orbital respecto a la eclíptica
+    #[serde(default)]
+    pub inclination: f32,
+    /// Longitud del nodo ascendente (Ω)
+    #[serde(default)]
+    pub longitude_of_ascending_node: f32,
+    /// Argumento del periapsis (ω)
+    #[serde(default)]
+    pub argument_of_periapsis: f32,
+    /// Anomalía media en t = 0
+    #[serde(default)]
+    pub mean_anomaly: f32,
+    /// Segundos simulados por vuelta (negativo = órbita retrógrada)
+    pub period: f32,
+}
+
+/// Resuelve la ecuación de Kepler M = E - e·sin(E) por Newton-Raphson y devuelve la
+/// anomalía excéntrica E (radianes) para una anomalía media M (radianes)
+pub fn solve_kepler(mean_anomaly: f32, eccentricity: f32) -> f32 {
+    let m = mean_anomaly.rem_euclid(TAU);
+    // Con excentricidades altas M es un mal punto de partida cerca del periapsis
+    let mut e_anomaly = if eccentricity < 0.8 { m } else { std::f32::consts::PI };
+    for _ in 0..KEPLER_ITERATIONS {
+        let f = e_anomaly - eccentricity * e_anomaly.sin() - m;
+        let step = f / (1.0 - eccentricity * e_anomaly.cos());
+        e_anomaly -= step;
+        if step.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+    e_anomaly
+}
+
+impl OrbitalElements {
+    /// Comprueba que la órbita sea una elipse válida
+    pub fn validate(&self) -> Result<(), String> {
+        if self.semi_major_axis <= 0.0 {
+            return Err("el semieje mayor debe ser positivo".to_string());
+        }
+        if !(0.0..1.0).contains(&self.eccentricity) {
+            return Err(format!("la excentricidad {} debe estar en [0, 1)", self.eccentricity));
+        }
+        Ok(())
+    }
+
+    /// Anomalía media (radianes) en el tiempo simulado `t`. Las vueltas completas se
+    /// descartan en f64: a x10000 `t` llega a 10⁷ s, donde un f32 solo distingue saltos de
+    /// ~1 s, más que una fracción apreciable de las órbitas cortas.
+    fn mean_anomaly_at(&self, t: f64) -> f32 {
+        let turns = if self.period != 0.0 { (t / self.period as f64).rem_euclid(1.0) } else { 0.0 };
+        (self.mean_anomaly.to_radians() as f64 + turns * std::f64::consts::TAU) as f32
+    }
+
+    /// Posición relativa al padre en el tiempo simulado `t`
+    pub fn position_at(&self, t: f64) -> Vec3 {
+        let mean_anomaly = self.mean_anomaly_at(t);
+        self.position_at_eccentric_anomaly(solve_kepler(mean_anomaly, self.eccentricity))
+    }
+
+    /// Punto de la elipse para una anomalía excéntrica dada; recorrer E de 0 a 2π dibuja
+    /// la órbita completa
+    pub fn position_at_eccentric_anomaly(&self, eccentric_anomaly: f32) -> Vec3 {
+        let a = self.semi_major_axis;
+        let e = self.eccentricity;
+
+        // Coordenadas en el plano de la órbita, con el periapsis sobre el eje x
+        let (sin_e, cos_e) = eccentric_anomaly.sin_cos();
+        let x = a * (cos_e - e);
+        let y = a * (1.0 - e * e).sqrt() * sin_e;
+        self.orbital_plane_to_world(x, y)
+    }
+
+    /// Velocidad relativa al padre en el tiempo simulado `t` para un cuerpo que recorre esta
+    /// elipse bajo la gravedad de un padre con parámetro gravitatorio `mu` (G·M). La posición
+    /// es la de `position_at`; la rapidez sale de `mu` y no del periodo de la escena.
+    pub fn velocity_at(&self, t: f64, mu: f32) -> Vec3 {
+        let a = self.semi_major_axis;
+        let e = self.eccentricity;
+        let mean_anomaly = self.mean_anomaly_at(t);
+        let (sin_e, cos_e) = solve_kepler(mean_anomaly, e).sin_cos();
+
+        // Derivada de la anomalía excéntrica con el movimiento medio de Kepler, sqrt(mu / a³)
+        let direction = if self.period < 0.0 { -1.0 } else { 1.0 };
+        let mean_motion = direction * (mu / (a * a * a)).sqrt();
+        let e_rate = mean_motion / (1.0 - e * cos_e);
+        let vx = -a * sin_e * e_rate;
+        let vy = a * (1.0 - e * e).sqrt() * cos_e * e_rate;
+        self.orbital_plane_to_world(vx, vy)
+    }
+
+    /// Lleva un vector del plano de la órbita (x hacia el periapsis) a coordenadas del mundo
+    fn orbital_plane_to_world(&self, x: f32, y: f32) -> Vec3 {
+        // Rotaciones ω, i y Ω hacia la eclíptica
+        let (sin_w, cos_w) = self.argument_of_periapsis.to_radians().sin_cos();
+        let (sin_i, cos_i) = self.inclination.to_radians().sin_cos();
+        let (sin_o, cos_o) = self.longitude_of_ascending_node.to_radians().sin_cos();
+
+        let ecliptic_x = (cos_o * cos_w - sin_o * sin_w * cos_i) * x + (-cos_o * sin_w - sin_o * cos_w * cos_i) * y;
+        let ecliptic_y = (sin_o * cos_w + cos_o * sin_w * cos_i) * x + (-sin_o * sin_w + cos_o * cos_w * cos_i) * y;
+        let ecliptic_z = (sin_w * sin_i) * x + (cos_w * sin_i) * y;
+
+        // Eclíptica → mundo: el eje y de la eclíptica es +Z y el norte es +Y. El mundo queda
+        // reflejado respecto al cielo real; `Ephemeris::apply` lo compensa
+        Vec3::new(ecliptic_x, ecliptic_z, ecliptic_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(eccentricity: f32) -> OrbitalElements {
+        OrbitalElements {
+            semi_major_axis: 100.0,
+            eccentricity,
+            inclination: 0.0,
+            longitude_of_ascending_node: 0.0,
+            argument_of_periapsis: 0.0,
+            mean_anomaly: 0.0,
+            period: 10.0,
+        }
+    }
+
+    #[test]
+    fn kepler_residual() {
+        for eccentricity in [0.0, 0.2056, 0.9] {
+            for step in 0..64 {
+                let mean_anomaly = TAU * step as f32 / 64.0;
+                let e_anomaly = solve_kepler(mean_anomaly, eccentricity);
+                let residual = e_anomaly - eccentricity * e_anomaly.sin() - mean_anomaly;
+                assert!(
+                    residual.abs() < 1e-6,
+                    "e = {}, M = {}: residuo {}", eccentricity, mean_anomaly, residual
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn starts_at_periapsis() {
+        for eccentricity in [0.0, 0.2056, 0.9] {
+            let orbit = elements(eccentricity);
+            let r = orbit.position_at(0.0).length();
+            let expected = orbit.semi_major_axis * (1.0 - eccentricity);
+            assert!((r - expected).abs() < 1e-3, "e = {}: r = {}, se esperaba {}", eccentricity, r, expected);
+        }
+    }
+
+    #[test]
+    fn precise_after_many_orbits() {
+        // Dos millones de vueltas de 7.3 s, como Mercurio tras media hora a x10000
+        let orbit = OrbitalElements { period: 7.3, ..elements(0.2056) };
+        let late = orbit.position_at(2_000_000.0 * orbit.period as f64 + 1.0);
+        let early = orbit.position_at(1.0);
+        assert!((late - early).length() < 1e-2, "{} frente a {}", late, early);
+    }
+}
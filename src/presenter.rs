@@ -0,0 +1,18 @@
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+use crate::framebuffer::Framebuffer;
+
+/// Copia el framebuffer de CPU a una textura de streaming y la muestra en la ventana.
+/// La textura debe haberse creado con formato RGB24 y el mismo tamaño que el framebuffer.
+pub fn present(
+    canvas: &mut Canvas<Window>,
+    texture: &mut Texture,
+    framebuffer: &Framebuffer,
+) -> Result<(), String> {
+    texture
+        .update(None, framebuffer.as_bytes(), framebuffer.width() * 3)
+        .map_err(|e| e.to_string())?;
+    canvas.copy(texture, None, None)?;
+    canvas.present();
+    Ok(())
+}
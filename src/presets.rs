@@ -0,0 +1,138 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::assets::{AssetCache, AssetError};
+use crate::shaders::{Shader, Sun, RockyPlanet, Moon, Mercury, Venus, Mars, Jupiter, Saturn, Uranus, Neptune, Ring};
+
+/// Archivo de presets que cargan la ventana y el render sin ventana
+pub const SHADER_PRESETS_PATH: &str = "config/shaders.toml";
+
+/// Uno de los shaders incluidos con sus parámetros. En el archivo la clave `shader` elige
+/// el tipo y el resto de claves sobrescribe sus valores por defecto.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "shader", rename_all = "snake_case")]
+pub enum ShaderPreset {
+    Sun(Sun),
+    RockyPlanet(RockyPlanet),
+    Moon(Moon),
+    Mercury(Mercury),
+    Venus(Venus),
+    Mars(Mars),
+    Jupiter(Jupiter),
+    Saturn(Saturn),
+    Uranus(Uranus),
+    Neptune(Neptune),
+    Ring(Ring),
+}
+
+impl ShaderPreset {
+    pub fn shader(&self) -> &dyn Shader {
+        match self {
+            ShaderPreset::Sun(shader) => shader,
+            ShaderPreset::RockyPlanet(shader) => shader,
+            ShaderPreset::Moon(shader) => shader,
+            ShaderPreset::Mercury(shader) => shader,
+            ShaderPreset::Venus(shader) => shader,
+            ShaderPreset::Mars(shader) => shader,
+            ShaderPreset::Jupiter(shader) => shader,
+            ShaderPreset::Saturn(shader) => shader,
+            ShaderPreset::Uranus(shader) => shader,
+            ShaderPreset::Neptune(shader) => shader,
+            ShaderPreset::Ring(shader) => shader,
+        }
+    }
+}
+
+/// Presets de shaders por nombre: los de los cuerpos del sistema solar más las variantes
+/// definidas en el archivo
+pub struct ShaderPresets {
+    presets: HashMap<String, ShaderPreset>,
+}
+
+impl Default for ShaderPresets {
+    /// Los cuerpos del sistema solar con los parámetros originales
+    fn default() -> Self {
+        let presets = [
+            ("sol", ShaderPreset::Sun(Sun::default())),
+            ("tierra", ShaderPreset::RockyPlanet(RockyPlanet::default())),
+            ("luna", ShaderPreset::Moon(Moon::default())),
+            ("mercurio", ShaderPreset::Mercury(Mercury::default())),
+            ("venus", ShaderPreset::Venus(Venus::default())),
+            ("marte", ShaderPreset::Mars(Mars::default())),
+            ("jupiter", ShaderPreset::Jupiter(Jupiter::default())),
+            ("saturno", ShaderPreset::Saturn(Saturn::default())),
+            ("anillos_saturno", ShaderPreset::Ring(Ring::default())),
+            ("urano", ShaderPreset::Uranus(Uranus::default())),
+            // Anillos de Urano: estrechos, oscuros y casi transparentes
+            ("anillos_urano", ShaderPreset::Ring(Ring {
+                inner_color: [75.0, 75.0, 80.0],
+                outer_color: [105.0, 105.0, 110.0],
+                ringlet_freq: 40.0,
+                ringlet_contrast: 0.2,
+                opacity: 0.45,
+                gap_threshold: 1.0,
+                ambient: 0.1,
+                ..Ring::default()
+            })),
+            ("neptuno", ShaderPreset::Neptune(Neptune::default())),
+            // Planetas enanos: superficies craterizadas como la de la Luna
+            ("ceres", ShaderPreset::Moon(Moon {
+                dark_color: [70.0, 68.0, 66.0],
+                mid_color: [110.0, 107.0, 104.0],
+                light_color: [150.0, 146.0, 140.0],
+                ..Moon::default()
+            })),
+            ("pluton", ShaderPreset::Moon(Moon {
+                dark_color: [110.0, 75.0, 50.0],
+                mid_color: [185.0, 155.0, 120.0],
+                light_color: [235.0, 220.0, 195.0],
+                crater_freq: 10.0,
+                crater_threshold: 0.82,
+            })),
+        ];
+        ShaderPresets {
+            presets: presets.into_iter().map(|(name, preset)| (name.to_string(), preset)).collect(),
+        }
+    }
+}
+
+impl ShaderPresets {
+    /// Lee un archivo TOML con una tabla por preset; los nombres que ya existen se reemplazan.
+    /// Los mapas de color (`texture`) se buscan relativos al archivo y se cargan con `assets`.
+    pub fn load(path: impl AsRef<Path>, assets: &mut AssetCache) -> Result<Self, AssetError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => AssetError::MissingFile { path: path.to_path_buf() },
+            _ => AssetError::Io { path: path.to_path_buf(), source },
+        })?;
+        let mut loaded: HashMap<String, ShaderPreset> = toml::from_str(&content)
+            .map_err(|e| AssetError::Parse { path: path.to_path_buf(), message: e.to_string() })?;
+
+        let directory = path.parent().unwrap_or(Path::new(""));
+        for preset in loaded.values_mut() {
+            if let ShaderPreset::RockyPlanet(shader) = preset {
+                if let Some(texture) = &shader.texture {
+                    shader.texture_map = Some(assets.load_texture(directory.join(texture))?);
+                }
+            }
+        }
+
+        let mut presets = ShaderPresets::default();
+        presets.presets.extend(loaded);
+        Ok(presets)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Shader> {
+        self.presets.get(name).map(ShaderPreset::shader)
+    }
+
+    /// Preset de anillos por nombre (`None` si no existe o es de otro tipo)
+    pub fn ring(&self, name: &str) -> Option<&Ring> {
+        match self.presets.get(name) {
+            Some(ShaderPreset::Ring(ring)) => Some(ring),
+            _ => None,
+        }
+    }
+}
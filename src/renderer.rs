@@ -1,273 +1,624 @@
 use sdl2::pixels::Color;
-use sdl2::rect::Point;
-use glam::{Vec3, Mat4};
-use crate::framebuffer::ZBuffer;
-use crate::shader_type::ShaderType;
-use crate::shaders::apply_shader;
+use glam::{Vec2, Vec3, Vec4, Mat4};
+use rayon::prelude::*;
+use crate::framebuffer::{Framebuffer, ZBuffer, Tile, split_tiles};
+use crate::shaders::{Fragment, Shader};
 use crate::utils::create_model_matrix;
 
-pub const SCREEN_WIDTH: u32 = 800;
-pub const SCREEN_HEIGHT: u32 = 600;
+pub const DEFAULT_WIDTH: u32 = 800;
+pub const DEFAULT_HEIGHT: u32 = 600;
 
-pub fn fill_triangle_zbuffer(
-    canvas: &mut sdl2::render::Canvas<sdl2::video::Window>,
-    zbuffer: &mut ZBuffer,
-    mut p0: Point, mut p1: Point, mut p2: Point,
-    mut z0: f32, mut z1: f32, mut z2: f32,
-    color: Color
-) {
-    let in_bounds = |p: &Point| {
-        p.x >= 0 && p.x < SCREEN_WIDTH as i32 && p.y >= 0 && p.y < SCREEN_HEIGHT as i32
-    };
-    
-    if !in_bounds(&p0) && !in_bounds(&p1) && !in_bounds(&p2) {
-        return;
+/// Filas por franja de rasterizado: cada franja la procesa un solo hilo
+const TILE_ROWS: usize = 16;
+
+/// Contadores de un cuadro: cuánto trabajo se dibujó y cuánto se descartó antes de rasterizar
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderStats {
+    pub objects_drawn: u32,
+    pub objects_culled: u32,
+    pub triangles_drawn: u32,
+    pub triangles_culled: u32,
+}
+
+/// Estado de render compartido por todos los objetos de un cuadro: resolución,
+/// buffers de color y profundidad, y las matrices de cámara y proyección.
+pub struct RenderContext {
+    pub framebuffer: Framebuffer,
+    pub zbuffer: ZBuffer,
+    width: u32,
+    height: u32,
+    pub fov_y: f32,
+    pub near: f32,
+    pub far: f32,
+    /// Posición de la luz puntual en el mundo (el Sol está en el origen)
+    pub light_position: Vec3,
+    /// Descartar triángulos que dan la espalda a la cámara (desactivar para mallas abiertas)
+    pub backface_culling: bool,
+    /// Rasterizar las franjas en paralelo; el resultado es idéntico al de un solo hilo
+    pub parallel: bool,
+    pub stats: RenderStats,
+    camera_position: Vec3,
+    view: Mat4,
+    projection: Mat4,
+}
+
+impl RenderContext {
+    pub fn new(width: u32, height: u32) -> Self {
+        let width = width.max(1);
+        let height = height.max(1);
+        let mut context = RenderContext {
+            framebuffer: Framebuffer::new(width as usize, height as usize),
+            zbuffer: ZBuffer::new(width as usize, height as usize),
+            width,
+            height,
+            fov_y: std::f32::consts::FRAC_PI_4,
+            near: 1.0,
+            far: 50000.0,
+            light_position: Vec3::ZERO,
+            backface_culling: true,
+            parallel: true,
+            stats: RenderStats::default(),
+            camera_position: Vec3::ZERO,
+            view: Mat4::IDENTITY,
+            projection: Mat4::IDENTITY,
+        };
+        context.update_projection();
+        context
     }
-    
-    if p0.y > p1.y { 
-        std::mem::swap(&mut p0, &mut p1);
-        std::mem::swap(&mut z0, &mut z1);
+
+    pub fn width(&self) -> u32 {
+        self.width
     }
-    if p0.y > p2.y { 
-        std::mem::swap(&mut p0, &mut p2);
-        std::mem::swap(&mut z0, &mut z2);
+
+    pub fn height(&self) -> u32 {
+        self.height
     }
-    if p1.y > p2.y { 
-        std::mem::swap(&mut p1, &mut p2);
-        std::mem::swap(&mut z1, &mut z2);
+
+    /// Reasigna los buffers y recalcula la proyección para la nueva resolución
+    pub fn resize(&mut self, width: u32, height: u32) {
+        let width = width.max(1);
+        let height = height.max(1);
+        if width == self.width && height == self.height {
+            return;
+        }
+        self.width = width;
+        self.height = height;
+        self.framebuffer = Framebuffer::new(width as usize, height as usize);
+        self.zbuffer = ZBuffer::new(width as usize, height as usize);
+        self.update_projection();
     }
-    
-    canvas.set_draw_color(color);
-    
-    let total_height = p2.y - p0.y;
-    if total_height == 0 { return; }
-    
-    let y_start = p0.y.max(0);
-    let y_end = p2.y.min(SCREEN_HEIGHT as i32 - 1);
-    
-    for y in y_start..=y_end {
-        let is_upper_half = y <= p1.y;
-        let segment_height = if is_upper_half { p1.y - p0.y } else { p2.y - p1.y };
-        
-        if segment_height == 0 { continue; }
-        
-        let alpha = (y - p0.y) as f32 / total_height as f32;
-        let beta = if is_upper_half {
-            if p1.y == p0.y { 0.0 } else { (y - p0.y) as f32 / (p1.y - p0.y) as f32 }
-        } else {
-            if p2.y == p1.y { 0.0 } else { (y - p1.y) as f32 / (p2.y - p1.y) as f32 }
-        };
-        
-        let ax = p0.x as f32 + (p2.x - p0.x) as f32 * alpha;
-        let az = z0 + (z2 - z0) * alpha;
-        
-        let (bx, bz) = if is_upper_half {
-            (p0.x as f32 + (p1.x - p0.x) as f32 * beta, z0 + (z1 - z0) * beta)
-        } else {
-            (p1.x as f32 + (p2.x - p1.x) as f32 * beta, z1 + (z2 - z1) * beta)
-        };
-        
-        let (x_start, x_end, z_start, z_end) = if ax < bx {
-            (ax as i32, bx as i32, az, bz)
+
+    pub fn update_projection(&mut self) {
+        let aspect = self.width as f32 / self.height as f32;
+        self.projection = Mat4::perspective_rh_gl(self.fov_y, aspect, self.near, self.far);
+    }
+
+    pub fn set_camera(&mut self, position: Vec3, target: Vec3) {
+        self.camera_position = position;
+        self.view = Mat4::look_at_rh(position, target, Vec3::Y);
+    }
+
+    pub fn camera_position(&self) -> Vec3 {
+        self.camera_position
+    }
+
+    pub fn view_projection(&self) -> Mat4 {
+        self.projection * self.view
+    }
+
+    /// Convierte coordenadas normalizadas de dispositivo a píxeles de pantalla
+    pub fn ndc_to_screen(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            (x + 1.0) * 0.5 * self.width as f32,
+            (1.0 - (y + 1.0) * 0.5) * self.height as f32,
+        )
+    }
+
+    /// Radio aproximado en píxeles de una esfera proyectada (infinito si la cámara está dentro)
+    pub fn projected_radius(&self, center: Vec3, radius: f32) -> f32 {
+        let distance = (center - self.camera_position).length();
+        if distance <= radius {
+            return f32::INFINITY;
+        }
+        radius / (distance * (self.fov_y * 0.5).tan()) * self.height as f32 * 0.5
+    }
+
+    /// Planos del frustum en espacio del mundo (Gribb-Hartmann), normalizados y con la
+    /// normal hacia dentro: un punto está dentro si dot(n, p) + d >= 0
+    fn frustum_planes(&self) -> [Vec4; 6] {
+        let m = self.view_projection();
+        let (r0, r1, r2, r3) = (m.row(0), m.row(1), m.row(2), m.row(3));
+        [r3 + r2, r3 - r2, r3 + r0, r3 - r0, r3 + r1, r3 - r1]
+            .map(|plane| plane / plane.truncate().length())
+    }
+
+    /// Indica si una esfera envolvente en espacio del mundo toca el frustum
+    pub fn is_sphere_visible(&self, center: Vec3, radius: f32) -> bool {
+        self.frustum_planes()
+            .iter()
+            .all(|plane| plane.truncate().dot(center) + plane.w >= -radius)
+    }
+
+    /// Prueba de visibilidad de un objeto completo que además actualiza las estadísticas.
+    /// Devuelve `true` si el objeto quedó fuera y no hay que dibujarlo.
+    pub fn cull_sphere(&mut self, center: Vec3, radius: f32) -> bool {
+        let culled = !self.is_sphere_visible(center, radius);
+        if culled {
+            self.stats.objects_culled += 1;
         } else {
-            (bx as i32, ax as i32, bz, az)
-        };
-        
-        let x_start = x_start.max(0);
-        let x_end = x_end.min(SCREEN_WIDTH as i32 - 1);
-        
-        if x_start <= x_end {
-            for x in x_start..=x_end {
-                let t = if x_end == x_start { 
-                    0.0 
-                } else { 
-                    (x - x_start) as f32 / (x_end - x_start) as f32 
+            self.stats.objects_drawn += 1;
+        }
+        culled
+    }
+
+    /// Limpia el color, la profundidad y las estadísticas al comienzo de un cuadro
+    pub fn clear(&mut self, color: Color) {
+        self.framebuffer.clear(color);
+        self.zbuffer.clear();
+        self.stats = RenderStats::default();
+    }
+}
+
+/// Atributos que se interpolan por píxel entre los vértices de un triángulo
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Varyings {
+    pub position: Vec3,       // Espacio del modelo (patrones procedurales)
+    pub normal: Vec3,         // Espacio del modelo
+    pub world_position: Vec3,
+    pub world_normal: Vec3,
+    pub uv: Vec2,
+}
+
+impl Varyings {
+    /// Combinación ponderada de los atributos de los tres vértices
+    fn blend(v: [&Varyings; 3], w: [f32; 3]) -> Varyings {
+        Varyings {
+            position: v[0].position * w[0] + v[1].position * w[1] + v[2].position * w[2],
+            normal: v[0].normal * w[0] + v[1].normal * w[1] + v[2].normal * w[2],
+            world_position: v[0].world_position * w[0] + v[1].world_position * w[1] + v[2].world_position * w[2],
+            world_normal: v[0].world_normal * w[0] + v[1].world_normal * w[1] + v[2].world_normal * w[2],
+            uv: v[0].uv * w[0] + v[1].uv * w[1] + v[2].uv * w[2],
+        }
+    }
+
+    fn lerp(&self, other: &Varyings, t: f32) -> Varyings {
+        Varyings {
+            position: self.position.lerp(other.position, t),
+            normal: self.normal.lerp(other.normal, t),
+            world_position: self.world_position.lerp(other.world_position, t),
+            world_normal: self.world_normal.lerp(other.world_normal, t),
+            uv: self.uv.lerp(other.uv, t),
+        }
+    }
+}
+
+/// Derivadas de pantalla de las UV: cuánto cambian al avanzar un píxel en x y en y.
+/// Sirven para elegir el mipmap de las texturas.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UvDerivatives {
+    pub dx: Vec2,
+    pub dy: Vec2,
+}
+
+/// Vértice en espacio de recorte homogéneo, antes de la división de perspectiva
+#[derive(Debug, Clone, Copy)]
+struct ClipVertex {
+    position: Vec4,
+    varyings: Varyings,
+}
+
+/// Planos del frustum en espacio de recorte: un punto está dentro si dot(plano, p) >= 0
+const CLIP_PLANES: [Vec4; 6] = [
+    Vec4::new(0.0, 0.0, 1.0, 1.0),  // Cercano: z >= -w
+    Vec4::new(0.0, 0.0, -1.0, 1.0), // Lejano: z <= w
+    Vec4::new(1.0, 0.0, 0.0, 1.0),  // Izquierdo
+    Vec4::new(-1.0, 0.0, 0.0, 1.0), // Derecho
+    Vec4::new(0.0, 1.0, 0.0, 1.0),  // Inferior
+    Vec4::new(0.0, -1.0, 0.0, 1.0), // Superior
+];
+
+/// Bits de los planos del frustum que deja fuera cada vértice
+fn outcode(p: Vec4) -> u8 {
+    CLIP_PLANES
+        .iter()
+        .enumerate()
+        .fold(0, |code, (i, plane)| if plane.dot(p) < 0.0 { code | (1 << i) } else { code })
+}
+
+/// Recorta un polígono convexo contra los planos indicados en `mask` (Sutherland-Hodgman).
+/// Los atributos se interpolan antes de la división por w, donde son lineales.
+fn clip_polygon(mut polygon: Vec<ClipVertex>, mask: u8) -> Vec<ClipVertex> {
+    for (i, plane) in CLIP_PLANES.iter().enumerate() {
+        if mask & (1 << i) == 0 { continue; }
+
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+        for (n, current) in polygon.iter().enumerate() {
+            let next = &polygon[(n + 1) % polygon.len()];
+            let d_current = plane.dot(current.position);
+            let d_next = plane.dot(next.position);
+
+            if d_current >= 0.0 {
+                clipped.push(*current);
+            }
+            if (d_current >= 0.0) != (d_next >= 0.0) {
+                let t = d_current / (d_current - d_next);
+                clipped.push(ClipVertex {
+                    position: current.position.lerp(next.position, t),
+                    varyings: current.varyings.lerp(&next.varyings, t),
+                });
+            }
+        }
+
+        polygon = clipped;
+        if polygon.len() < 3 { return Vec::new(); }
+    }
+    polygon
+}
+
+/// Vértice ya proyectado: posición en píxeles, profundidad en [0, 1] y 1/w
+/// para la corrección de perspectiva
+#[derive(Debug, Clone, Copy)]
+pub struct ScreenVertex {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub inv_w: f32,
+    pub varyings: Varyings,
+}
+
+/// Área con signo de un polígono en pantalla. Con y hacia abajo, las caras frontales
+/// (antihorarias en NDC) tienen área negativa.
+fn signed_area(polygon: &[ScreenVertex]) -> f32 {
+    let mut area = 0.0;
+    for (n, a) in polygon.iter().enumerate() {
+        let b = &polygon[(n + 1) % polygon.len()];
+        area += a.x * b.y - b.x * a.y;
+    }
+    area * 0.5
+}
+
+fn edge(a: &ScreenVertex, b: &ScreenVertex, px: f32, py: f32) -> f32 {
+    (b.x - a.x) * (py - a.y) - (b.y - a.y) * (px - a.x)
+}
+
+/// Regla top-left: los píxeles sobre una arista compartida pertenecen a un solo triángulo
+fn is_top_left(a: &ScreenVertex, b: &ScreenVertex) -> bool {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    (dy == 0.0 && dx > 0.0) || dy < 0.0
+}
+
+/// Rasteriza la parte de un triángulo que cae dentro de una franja, con prueba de
+/// profundidad, interpolando los atributos con corrección de perspectiva y llamando
+/// a `shade` por cada fragmento visible. Los fragmentos transparentes se mezclan con el
+/// color del píxel según su alfa y no escriben profundidad.
+fn fill_triangle<F: Fn(&Varyings, UvDerivatives) -> Color>(
+    tile: &mut Tile,
+    vertices: &[ScreenVertex; 3],
+    transparent: bool,
+    shade: &F,
+) {
+    let (v0, mut v1, mut v2) = (&vertices[0], &vertices[1], &vertices[2]);
+
+    let mut area = edge(v0, v1, v2.x, v2.y);
+    if area.abs() < 1e-8 { return; }
+    if area < 0.0 {
+        std::mem::swap(&mut v1, &mut v2);
+        area = -area;
+    }
+
+    let width = tile.width() as i32;
+
+    let x_start = (v0.x.min(v1.x).min(v2.x).floor() as i32).max(0);
+    let x_end = (v0.x.max(v1.x).max(v2.x).ceil() as i32).min(width - 1);
+    let y_start = (v0.y.min(v1.y).min(v2.y).floor() as i32).max(tile.y_start() as i32);
+    let y_end = (v0.y.max(v1.y).max(v2.y).ceil() as i32).min(tile.y_end() as i32 - 1);
+
+    if x_start > x_end || y_start > y_end { return; }
+
+    let top_left = [is_top_left(v1, v2), is_top_left(v2, v0), is_top_left(v0, v1)];
+    let inside = |w: f32, i: usize| w > 0.0 || (w == 0.0 && top_left[i]);
+
+    // Variación de las baricéntricas por píxel, para derivar las UV en pantalla
+    let dl_dx = [(v1.y - v2.y) / area, (v2.y - v0.y) / area, (v0.y - v1.y) / area];
+    let dl_dy = [(v2.x - v1.x) / area, (v0.x - v2.x) / area, (v1.x - v0.x) / area];
+    let perspective_uv = |l: [f32; 3]| {
+        let p = [l[0] * v0.inv_w, l[1] * v1.inv_w, l[2] * v2.inv_w];
+        (v0.varyings.uv * p[0] + v1.varyings.uv * p[1] + v2.varyings.uv * p[2]) / (p[0] + p[1] + p[2])
+    };
+
+    for y in y_start..=y_end {
+        let py = y as f32 + 0.5;
+        for x in x_start..=x_end {
+            let px = x as f32 + 0.5;
+
+            let w0 = edge(v1, v2, px, py);
+            let w1 = edge(v2, v0, px, py);
+            let w2 = edge(v0, v1, px, py);
+            if !(inside(w0, 0) && inside(w1, 1) && inside(w2, 2)) { continue; }
+
+            // Coordenadas baricéntricas en pantalla: la profundidad (z/w) es lineal aquí
+            let l0 = w0 / area;
+            let l1 = w1 / area;
+            let l2 = w2 / area;
+            let z = l0 * v0.z + l1 * v1.z + l2 * v2.z;
+
+            let visible = if transparent { tile.test(x, y, z) } else { tile.test_and_set(x, y, z) };
+            if visible {
+                // Los atributos se interpolan linealmente en 1/w (corrección de perspectiva)
+                let p0 = l0 * v0.inv_w;
+                let p1 = l1 * v1.inv_w;
+                let p2 = l2 * v2.inv_w;
+                let sum = p0 + p1 + p2;
+                let varyings = Varyings::blend(
+                    [&v0.varyings, &v1.varyings, &v2.varyings],
+                    [p0 / sum, p1 / sum, p2 / sum],
+                );
+                let derivatives = UvDerivatives {
+                    dx: perspective_uv([l0 + dl_dx[0], l1 + dl_dx[1], l2 + dl_dx[2]]) - varyings.uv,
+                    dy: perspective_uv([l0 + dl_dy[0], l1 + dl_dy[1], l2 + dl_dy[2]]) - varyings.uv,
                 };
-                let z = z_start + (z_end - z_start) * t;
-                
-                if zbuffer.test_and_set(x, y, z) {
-                    let _ = canvas.draw_point(Point::new(x, y));
+                let color = shade(&varyings, derivatives);
+                if transparent {
+                    tile.blend_pixel(x, y, color);
+                } else {
+                    tile.set_pixel(x, y, color);
                 }
             }
         }
     }
 }
 
+/// Reparte los triángulos en franjas horizontales y las rasteriza en paralelo.
+/// Cada franja recorre sus triángulos en el orden de envío y ningún píxel pertenece
+/// a dos franjas, así que la prueba de profundidad da el mismo resultado que en serie.
+fn rasterize<F>(context: &mut RenderContext, triangles: &[[ScreenVertex; 3]], transparent: bool, shade: &F)
+where
+    F: Fn(&Varyings, UvDerivatives) -> Color + Sync,
+{
+    if triangles.is_empty() { return; }
+
+    let height = context.height as i32;
+    let mut bins: Vec<Vec<u32>> = vec![Vec::new(); (height as usize).div_ceil(TILE_ROWS)];
+    for (i, triangle) in triangles.iter().enumerate() {
+        let min_y = triangle.iter().map(|v| v.y).fold(f32::INFINITY, f32::min).floor() as i32;
+        let max_y = triangle.iter().map(|v| v.y).fold(f32::NEG_INFINITY, f32::max).ceil() as i32;
+        if max_y < 0 || min_y >= height { continue; }
+        let first = min_y.max(0) as usize / TILE_ROWS;
+        let last = max_y.min(height - 1) as usize / TILE_ROWS;
+        for bin in &mut bins[first..=last] {
+            bin.push(i as u32);
+        }
+    }
+
+    let parallel = context.parallel;
+    let tiles = split_tiles(&mut context.framebuffer, &mut context.zbuffer, TILE_ROWS);
+    let draw_tile = |(mut tile, bin): (Tile, &Vec<u32>)| {
+        for &i in bin {
+            fill_triangle(&mut tile, &triangles[i as usize], transparent, shade);
+        }
+    };
+    if parallel {
+        tiles.into_par_iter().zip(bins.par_iter()).for_each(draw_tile);
+    } else {
+        tiles.into_iter().zip(bins.iter()).for_each(draw_tile);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn render(
-    canvas: &mut sdl2::render::Canvas<sdl2::video::Window>, 
-    zbuffer: &mut ZBuffer, 
-    model: &tobj::Model, 
-    camera_position: Vec3,
-    camera_target: Vec3,
+    context: &mut RenderContext,
+    model: &tobj::Model,
     world_position: Vec3,
     model_center: Vec3,
     model_scale: f32,
     rotation_y: f32,
-    shader_type: ShaderType,
+    shader: &dyn Shader,
     time: f32,
 ) {
-    let positions = &model.mesh.positions;
-    let indices = &model.mesh.indices;
-
-    let projection = Mat4::perspective_rh_gl(std::f32::consts::FRAC_PI_4, SCREEN_WIDTH as f32 / SCREEN_HEIGHT as f32, 1.0, 50000.0);
-    let view = Mat4::look_at_rh(
-        camera_position,
-        camera_target,
-        Vec3::Y,
-    );
-    
-    let rotation_vec = Vec3::new(0.0, rotation_y, 0.0);
-    let model_matrix = create_model_matrix(
+    render_with_full_rotation(
+        context,
+        model,
         world_position,
         model_center,
         model_scale,
-        rotation_vec
+        Vec3::new(0.0, rotation_y, 0.0),
+        shader,
+        time
     );
-
-    let mvp = projection * view * model_matrix;
-
-    for i in (0..indices.len()).step_by(3) {
-        let i0 = indices[i] as usize;
-        let i1 = indices[i + 1] as usize;
-        let i2 = indices[i + 2] as usize;
-
-        let v0 = Vec3::new(positions[3 * i0], positions[3 * i0 + 1], positions[3 * i0 + 2]);
-        let v1 = Vec3::new(positions[3 * i1], positions[3 * i1 + 1], positions[3 * i1 + 2]);
-        let v2 = Vec3::new(positions[3 * i2], positions[3 * i2 + 1], positions[3 * i2 + 2]);
-
-        let p0 = mvp * v0.extend(1.0);
-        let p1 = mvp * v1.extend(1.0);
-        let p2 = mvp * v2.extend(1.0);
-
-        let screen_p0 = Point::new(
-            ((p0.x / p0.w + 1.0) * 0.5 * SCREEN_WIDTH as f32) as i32,
-            ((1.0 - (p0.y / p0.w + 1.0) * 0.5) * SCREEN_HEIGHT as f32) as i32,
-        );
-        let screen_p1 = Point::new(
-            ((p1.x / p1.w + 1.0) * 0.5 * SCREEN_WIDTH as f32) as i32,
-            ((1.0 - (p1.y / p1.w + 1.0) * 0.5) * SCREEN_HEIGHT as f32) as i32,
-        );
-        let screen_p2 = Point::new(
-            ((p2.x / p2.w + 1.0) * 0.5 * SCREEN_WIDTH as f32) as i32,
-            ((1.0 - (p2.y / p2.w + 1.0) * 0.5) * SCREEN_HEIGHT as f32) as i32,
-        );
-
-        let edge1 = v1 - v0;
-        let edge2 = v2 - v0;
-        let normal = edge1.cross(edge2).normalize_or_zero();
-        
-        let light_dir = Vec3::new(0.5, 0.7, 1.0).normalize();
-        let intensity = normal.dot(light_dir).max(0.0);
-        
-        let avg_position = (v0 + v1 + v2) / 3.0;
-        
-        let (r, g, b) = apply_shader(
-            shader_type,
-            avg_position,
-            normal,
-            intensity,
-            time
-        );
-        
-        let z0 = (p0.z / p0.w + 1.0) * 0.5;
-        let z1 = (p1.z / p1.w + 1.0) * 0.5;
-        let z2 = (p2.z / p2.w + 1.0) * 0.5;
-        
-        fill_triangle_zbuffer(
-            canvas,
-            zbuffer,
-            screen_p0, screen_p1, screen_p2,
-            z0, z1, z2,
-            Color::RGB(r, g, b)
-        );
-    }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_with_full_rotation(
-    canvas: &mut sdl2::render::Canvas<sdl2::video::Window>, 
-    zbuffer: &mut ZBuffer, 
-    model: &tobj::Model, 
-    camera_position: Vec3,
-    camera_target: Vec3,
+    context: &mut RenderContext,
+    model: &tobj::Model,
     world_position: Vec3,
     model_center: Vec3,
     model_scale: f32,
     rotation: Vec3,
-    shader_type: ShaderType,
+    shader: &dyn Shader,
     time: f32,
 ) {
-    let positions = &model.mesh.positions;
-    let indices = &model.mesh.indices;
-
-    let projection = Mat4::perspective_rh_gl(std::f32::consts::FRAC_PI_4, SCREEN_WIDTH as f32 / SCREEN_HEIGHT as f32, 1.0, 50000.0);
-    let view = Mat4::look_at_rh(
-        camera_position,
-        camera_target,
-        Vec3::Y,
-    );
-    
     let model_matrix = create_model_matrix(
         world_position,
         model_center,
         model_scale,
         rotation
     );
+    render_with_matrix(context, model, model_matrix, shader, time);
+}
 
-    let mvp = projection * view * model_matrix;
+/// Dibuja una malla con su matriz de modelo ya compuesta (por ejemplo, la de mundo de un
+/// nodo del grafo de escena). Las mallas con shader transparente deben dibujarse después
+/// de toda la geometría opaca y de atrás hacia delante.
+pub fn render_with_matrix(
+    context: &mut RenderContext,
+    model: &tobj::Model,
+    model_matrix: Mat4,
+    shader: &dyn Shader,
+    time: f32,
+) {
+    let light_position = context.light_position;
+    let camera_position = context.camera_position;
+    let is_emissive = shader.is_emissive();
+    let transparent = shader.is_transparent();
 
-    for i in (0..indices.len()).step_by(3) {
-        let i0 = indices[i] as usize;
-        let i1 = indices[i + 1] as usize;
-        let i2 = indices[i + 2] as usize;
-
-        let v0 = Vec3::new(positions[3 * i0], positions[3 * i0 + 1], positions[3 * i0 + 2]);
-        let v1 = Vec3::new(positions[3 * i1], positions[3 * i1 + 1], positions[3 * i1 + 2]);
-        let v2 = Vec3::new(positions[3 * i2], positions[3 * i2 + 1], positions[3 * i2 + 2]);
-
-        let p0 = mvp * v0.extend(1.0);
-        let p1 = mvp * v1.extend(1.0);
-        let p2 = mvp * v2.extend(1.0);
-
-        let screen_p0 = Point::new(
-            ((p0.x / p0.w + 1.0) * 0.5 * SCREEN_WIDTH as f32) as i32,
-            ((1.0 - (p0.y / p0.w + 1.0) * 0.5) * SCREEN_HEIGHT as f32) as i32,
-        );
-        let screen_p1 = Point::new(
-            ((p1.x / p1.w + 1.0) * 0.5 * SCREEN_WIDTH as f32) as i32,
-            ((1.0 - (p1.y / p1.w + 1.0) * 0.5) * SCREEN_HEIGHT as f32) as i32,
-        );
-        let screen_p2 = Point::new(
-            ((p2.x / p2.w + 1.0) * 0.5 * SCREEN_WIDTH as f32) as i32,
-            ((1.0 - (p2.y / p2.w + 1.0) * 0.5) * SCREEN_HEIGHT as f32) as i32,
-        );
-
-        let edge1 = v1 - v0;
-        let edge2 = v2 - v0;
-        let normal = edge1.cross(edge2).normalize_or_zero();
-        
-        let light_dir = Vec3::new(0.5, 0.7, 1.0).normalize();
-        let intensity = normal.dot(light_dir).max(0.0);
-        
-        let avg_position = (v0 + v1 + v2) / 3.0;
-        
-        let (r, g, b) = apply_shader(
-            shader_type,
-            avg_position,
-            normal,
+    // Iluminación en espacio del mundo: desde cada fragmento hacia la luz puntual
+    let shade = |frag: &Varyings, uv_derivatives: UvDerivatives| {
+        let world_normal = frag.world_normal.normalize_or_zero();
+        let light_dir = (light_position - frag.world_position).normalize_or_zero();
+        let intensity = if is_emissive { 1.0 } else { world_normal.dot(light_dir).max(0.0) };
+        shader.shade(&Fragment {
+            position: frag.position,
+            normal: frag.normal.normalize_or_zero(),
+            world_position: frag.world_position,
+            world_normal,
+            uv: frag.uv,
+            uv_derivatives,
+            light_dir,
+            view_dir: (camera_position - frag.world_position).normalize_or_zero(),
             intensity,
-            time
-        );
-        
-        let z0 = (p0.z / p0.w + 1.0) * 0.5;
-        let z1 = (p1.z / p1.w + 1.0) * 0.5;
-        let z2 = (p2.z / p2.w + 1.0) * 0.5;
-        
-        fill_triangle_zbuffer(
-            canvas,
-            zbuffer,
-            screen_p0, screen_p1, screen_p2,
-            z0, z1, z2,
-            Color::RGB(r, g, b)
-        );
+            time,
+        })
+    };
+
+    draw_mesh(context, model, model_matrix, transparent, &shade);
+}
+
+/// Transforma, recorta y descarta los triángulos de una malla y rasteriza el resto con `shade`
+fn draw_mesh<F>(context: &mut RenderContext, model: &tobj::Model, model_matrix: Mat4, transparent: bool, shade: &F)
+where
+    F: Fn(&Varyings, UvDerivatives) -> Color + Sync,
+{
+    let mesh = &model.mesh;
+    let positions = &mesh.positions;
+    let normals = &mesh.normals;
+    let texcoords = &mesh.texcoords;
+    let indices = &mesh.indices;
+
+    let mvp = context.view_projection() * model_matrix;
+    let backface_culling = context.backface_culling;
+
+    // Geometría en serie (transformación, recorte y culling); el rasterizado va por franjas
+    let mut triangles: Vec<[ScreenVertex; 3]> = Vec::with_capacity(indices.len() / 3);
+    for i in (0..indices.len()).step_by(3) {
+        let idx = [indices[i] as usize, indices[i + 1] as usize, indices[i + 2] as usize];
+
+        let position = |k: usize| Vec3::new(positions[3 * k], positions[3 * k + 1], positions[3 * k + 2]);
+        let v = [position(idx[0]), position(idx[1]), position(idx[2])];
+
+        // Normales suaves del OBJ si existen; si no, la normal de la cara
+        let face_normal = (v[1] - v[0]).cross(v[2] - v[0]).normalize_or_zero();
+        let normal = |k: usize| {
+            if normals.len() >= 3 * (k + 1) {
+                Vec3::new(normals[3 * k], normals[3 * k + 1], normals[3 * k + 2])
+            } else {
+                face_normal
+            }
+        };
+        let uv = |k: usize| {
+            if texcoords.len() >= 2 * (k + 1) {
+                Vec2::new(texcoords[2 * k], texcoords[2 * k + 1])
+            } else {
+                Vec2::ZERO
+            }
+        };
+
+        let triangle: [ClipVertex; 3] = std::array::from_fn(|n| {
+            let model_normal = normal(idx[n]);
+            ClipVertex {
+                position: mvp * v[n].extend(1.0),
+                varyings: Varyings {
+                    position: v[n],
+                    normal: model_normal,
+                    world_position: model_matrix.transform_point3(v[n]),
+                    world_normal: model_matrix.transform_vector3(model_normal),
+                    uv: uv(idx[n]),
+                },
+            }
+        });
+
+        let codes = triangle.map(|vertex| outcode(vertex.position));
+        // Todos los vértices fuera del mismo plano: el triángulo no se ve
+        if codes[0] & codes[1] & codes[2] != 0 {
+            context.stats.triangles_culled += 1;
+            continue;
+        }
+
+        let to_screen = |vertex: &ClipVertex| {
+            let p = vertex.position;
+            let inv_w = 1.0 / p.w;
+            let (x, y) = context.ndc_to_screen(p.x * inv_w, p.y * inv_w);
+            ScreenVertex {
+                x,
+                y,
+                z: (p.z * inv_w + 1.0) * 0.5,
+                inv_w,
+                varyings: vertex.varyings,
+            }
+        };
+
+        let crossing = codes[0] | codes[1] | codes[2];
+        if crossing == 0 {
+            let screen = [to_screen(&triangle[0]), to_screen(&triangle[1]), to_screen(&triangle[2])];
+            if backface_culling && signed_area(&screen) >= 0.0 {
+                context.stats.triangles_culled += 1;
+                continue;
+            }
+            context.stats.triangles_drawn += 1;
+            triangles.push(screen);
+            continue;
+        }
+
+        // El triángulo cruza el frustum: recortar y triangular el polígono en abanico.
+        // Tras el recorte todo queda delante del plano cercano y la orientación es válida.
+        let polygon: Vec<ScreenVertex> = clip_polygon(triangle.to_vec(), crossing)
+            .iter()
+            .map(to_screen)
+            .collect();
+        if polygon.len() < 3 || (backface_culling && signed_area(&polygon) >= 0.0) {
+            context.stats.triangles_culled += 1;
+            continue;
+        }
+        context.stats.triangles_drawn += 1;
+        for n in 1..polygon.len() - 1 {
+            triangles.push([polygon[0], polygon[n], polygon[n + 1]]);
+        }
+    }
+
+    rasterize(context, &triangles, transparent, shade);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::{annulus, uv_sphere};
+    use crate::shaders::{Moon, Ring};
+
+    /// Esfera opaca que cruza varias franjas de `TILE_ROWS` filas y anillos transparentes
+    /// encima, dibujados en paralelo o en un solo hilo
+    fn render_test_frame(parallel: bool) -> Vec<u8> {
+        let mut context = RenderContext::new(64, 48);
+        context.parallel = parallel;
+        context.light_position = Vec3::new(10.0, 10.0, 10.0);
+        context.set_camera(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO);
+        context.clear(Color::RGB(0, 0, 0));
+
+        let sphere = tobj::Model::new(uv_sphere(16, 32, 1.0), "esfera".to_string());
+        render_with_matrix(&mut context, &sphere, Mat4::IDENTITY, &Moon::default(), 0.0);
+
+        let rings = tobj::Model::new(annulus(1.24, 2.27, 64), "anillos".to_string());
+        context.backface_culling = false;
+        render_with_matrix(&mut context, &rings, Mat4::from_rotation_x(0.5), &Ring::default(), 0.0);
+
+        context.framebuffer.as_bytes().to_vec()
+    }
+
+    #[test]
+    fn parallel_matches_single_thread() {
+        let parallel = render_test_frame(true);
+        let serial = render_test_frame(false);
+        assert!(parallel.iter().any(|&c| c != 0), "el cuadro de prueba quedó vacío");
+        assert!(parallel == serial, "el render en paralelo difiere del de un solo hilo");
     }
 }
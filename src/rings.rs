@@ -0,0 +1,209 @@
+use glam::{Mat4, Vec2, Vec3};
+use sdl2::pixels::Color;
+use serde::Deserialize;
+use crate::mesh::annulus;
+use crate::renderer::UvDerivatives;
+use crate::shaders::{Fragment, Ring, Shader};
+
+/// Segmentos angulares de cada banda de anillos
+const RING_SEGMENTS: u32 = 160;
+
+/// Fracción de luz que queda en la sombra de un cuerpo
+const SHADOW_LIGHT: f32 = 0.15;
+
+/// Sistema de anillos de un cuerpo tal como se describe en el archivo de escena. Los anillos
+/// están en el plano ecuatorial del cuerpo (el XZ del mundo). Su shader es transparente
+/// (ver `shaders::Ring`), así que se dibujan después de los cuerpos opacos.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RingSystem {
+    /// Preset de shader de los anillos (ver `config/shaders.toml`)
+    pub shader: String,
+    /// Bandas `[interior, exterior]` en radios del cuerpo. Los huecos entre bandas, como la
+    /// división de Cassini, quedan vacíos.
+    pub bands: Vec<[f32; 2]>,
+}
+
+impl RingSystem {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.bands.is_empty() {
+            return Err("los anillos necesitan al menos una banda".to_string());
+        }
+        match self.bands.iter().find(|[inner, outer]| *inner < 1.0 || outer <= inner) {
+            Some([inner, outer]) => Err(format!(
+                "la banda [{}, {}] debe empezar fuera del cuerpo (radio >= 1) y acabar después de empezar",
+                inner, outer
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Hay alguna banda a `radius` radios del cuerpo
+    pub fn contains(&self, radius: f32) -> bool {
+        self.bands.iter().any(|[inner, outer]| (*inner..=*outer).contains(&radius))
+    }
+
+    /// Radio interior en radios del cuerpo
+    pub fn inner_radius(&self) -> f32 {
+        self.bands.iter().map(|[inner, _]| *inner).fold(f32::INFINITY, f32::min)
+    }
+
+    /// Radio exterior en radios del cuerpo
+    pub fn outer_radius(&self) -> f32 {
+        self.bands.iter().map(|[_, outer]| *outer).fold(1.0, f32::max)
+    }
+
+    /// El preset de los anillos con el degradado ajustado a las bandas
+    pub fn shader(&self, preset: &Ring) -> Ring {
+        Ring { inner_radius: self.inner_radius(), outer_radius: self.outer_radius(), ..*preset }
+    }
+
+    /// Malla de todas las bandas en radios del cuerpo
+    pub fn mesh(&self) -> tobj::Mesh {
+        let mut mesh = tobj::Mesh::default();
+        for [inner, outer] in &self.bands {
+            let band = annulus(*inner, *outer, RING_SEGMENTS);
+            let offset = (mesh.positions.len() / 3) as u32;
+            mesh.positions.extend_from_slice(&band.positions);
+            mesh.normals.extend_from_slice(&band.normals);
+            mesh.texcoords.extend_from_slice(&band.texcoords);
+            mesh.indices.extend(band.indices.iter().map(|index| index + offset));
+        }
+        mesh
+    }
+}
+
+/// Oscurece los fragmentos de `shader` que quedan en la sombra de una esfera, por ejemplo
+/// los anillos tras su planeta. El borde de la sombra se suaviza un 3% del radio.
+pub struct SphereShadow<'a> {
+    pub shader: &'a dyn Shader,
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl SphereShadow<'_> {
+    /// Luz que llega al punto (1 = toda, 0 = en plena sombra)
+    fn light(&self, fragment: &Fragment) -> f32 {
+        let to_center = self.center - fragment.world_position;
+        let along = to_center.dot(fragment.light_dir);
+        // La esfera está detrás del punto visto desde la luz
+        if along <= 0.0 {
+            return 1.0;
+        }
+        let miss_distance = (to_center - fragment.light_dir * along).length() / self.radius;
+        ((miss_distance - 0.97) / 0.06).clamp(0.0, 1.0)
+    }
+}
+
+/// Escala el color según la luz que llega (conserva el alfa de los shaders transparentes)
+fn darken(color: Color, light: f32) -> Color {
+    let light = SHADOW_LIGHT + (1.0 - SHADOW_LIGHT) * light;
+    let scale = |c: u8| (c as f32 * light) as u8;
+    Color::RGBA(scale(color.r), scale(color.g), scale(color.b), color.a)
+}
+
+impl Shader for SphereShadow<'_> {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        darken(self.shader.shade(fragment), self.light(fragment))
+    }
+
+    fn is_emissive(&self) -> bool {
+        self.shader.is_emissive()
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.shader.is_transparent()
+    }
+}
+
+/// Oscurece los fragmentos de `shader` (el cuerpo) que quedan a la sombra de sus anillos.
+/// El rayo hacia la luz se corta con el plano de los anillos y la luz que pasa es la que
+/// deja la opacidad del shader de los anillos en ese punto.
+pub struct RingShadow<'a> {
+    shader: &'a dyn Shader,
+    rings: &'a RingSystem,
+    ring_shader: &'a dyn Shader,
+    ring_matrix: Mat4,
+    world_to_rings: Mat4,
+    ring_normal: Vec3,
+}
+
+impl<'a> RingShadow<'a> {
+    /// `ring_matrix` es la matriz de mundo de la malla de los anillos
+    pub fn new(shader: &'a dyn Shader, rings: &'a RingSystem, ring_shader: &'a dyn Shader, ring_matrix: Mat4) -> Self {
+        RingShadow {
+            shader,
+            rings,
+            ring_shader,
+            ring_matrix,
+            world_to_rings: ring_matrix.inverse(),
+            ring_normal: ring_matrix.transform_vector3(Vec3::Y).normalize_or_zero(),
+        }
+    }
+
+    fn light(&self, fragment: &Fragment) -> f32 {
+        // En el espacio de los anillos su plano es y = 0 y las distancias van en radios del cuerpo
+        let origin = self.world_to_rings.transform_point3(fragment.world_position);
+        let direction = self.world_to_rings.transform_vector3(fragment.light_dir);
+        if direction.y.abs() < 1e-6 {
+            return 1.0;
+        }
+        let t = -origin.y / direction.y;
+        if t <= 0.0 {
+            return 1.0;
+        }
+        let hit = origin + direction * t;
+        if !self.rings.contains(Vec2::new(hit.x, hit.z).length()) {
+            return 1.0;
+        }
+
+        // El shader de los anillos visto desde la luz da la fracción de luz que bloquean
+        let opacity = self.ring_shader.shade(&Fragment {
+            position: hit,
+            normal: Vec3::Y,
+            world_position: self.ring_matrix.transform_point3(hit),
+            world_normal: self.ring_normal,
+            uv: Vec2::ZERO,
+            uv_derivatives: UvDerivatives::default(),
+            light_dir: fragment.light_dir,
+            view_dir: fragment.light_dir,
+            intensity: 1.0,
+            time: fragment.time,
+        }).a;
+        1.0 - opacity as f32 / 255.0
+    }
+}
+
+impl Shader for RingShadow<'_> {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let color = self.shader.shade(fragment);
+        // La cara nocturna ya está en la sombra del propio cuerpo: la de los anillos se
+        // desvanece al acercarse al terminador para no dejar un borde recto
+        let fade = (fragment.intensity * 10.0).min(1.0);
+        if fade <= 0.0 {
+            return color;
+        }
+        darken(color, 1.0 - (1.0 - self.light(fragment)) * fade)
+    }
+
+    fn is_emissive(&self) -> bool {
+        self.shader.is_emissive()
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.shader.is_transparent()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shader_gradient_follows_bands() {
+        let rings = RingSystem { shader: "anillos".to_string(), bands: vec![[2.0, 2.5], [1.5, 1.6]] };
+        let shader = rings.shader(&Ring::default());
+        assert_eq!((shader.inner_radius, shader.outer_radius), (1.5, 2.5));
+        assert_eq!(shader.opacity, Ring::default().opacity);
+    }
+}
@@ -0,0 +1,566 @@
+use sdl2::pixels::Color;
+use glam::{Vec3, Mat4};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::assets::AssetError;
+use crate::ephemeris;
+use crate::presets::ShaderPresets;
+use crate::lod::SphereLod;
+use crate::orbit::OrbitalElements;
+use crate::rings::{RingShadow, RingSystem, SphereShadow};
+use crate::renderer::{RenderContext, render_with_matrix};
+use crate::scene_graph::{NodeId, SceneGraph, Transform};
+use crate::shaders::{Ring, Shader};
+
+/// Radio de la esfera en espacio del modelo. Es el del antiguo `sphere.obj`: los patrones
+/// de los shaders se calculan sobre estas coordenadas.
+pub const SPHERE_MODEL_RADIUS: f32 = 110.0;
+
+/// Archivo de escena que cargan la ventana y el render sin ventana
+pub const SCENE_PATH: &str = "config/scene.toml";
+
+/// Segundos simulados tras los que vuelve a empezar el tiempo de los shaders (ver
+/// `Body::shader_time`). Una hora a x1; en f32 aún distingue fracciones de milisegundo.
+const SHADER_TIME_WRAP: f64 = 3600.0;
+
+fn default_orbit_color() -> [u8; 3] {
+    [90, 90, 90]
+}
+
+/// Cuerpo de la escena tal como se describe en el archivo. Sin `parent` el cuerpo queda
+/// fijo en el origen; con padre recorre la órbita de Kepler de `orbit` alrededor de él
+/// (o lo acompaña sin moverse si no tiene órbita).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Body {
+    pub name: String,
+    /// Nombre del cuerpo alrededor del que orbita; debe aparecer antes en el archivo
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// Elementos orbitales relativos al padre
+    #[serde(default)]
+    pub orbit: Option<OrbitalElements>,
+    /// Elementos reales que sustituyen a los de `orbit` en el modo efemérides (`earth`,
+    /// `mars`, `moon`, ...)
+    #[serde(default)]
+    pub ephemeris: Option<String>,
+    /// Giro sobre su propio eje en rad/s (negativo = rotación retrógrada)
+    #[serde(default)]
+    pub spin: f32,
+    /// Radio en unidades del mundo
+    pub radius: f32,
+    /// Masa en masas solares; solo los cuerpos con masa participan en el modo N-cuerpos
+    #[serde(default)]
+    pub mass: Option<f32>,
+    /// Nombre del preset de shader (ver `config/shaders.toml`)
+    pub shader: String,
+    #[serde(default = "default_orbit_color")]
+    pub orbit_color: [u8; 3],
+    /// Anillos en el plano ecuatorial
+    #[serde(default)]
+    pub rings: Option<RingSystem>,
+    /// Planeta enano: se omite si la escena tiene `dwarf_planets = false`
+    #[serde(default)]
+    pub dwarf: bool,
+}
+
+impl Body {
+    /// Posición relativa al padre en el tiempo simulado `t`
+    pub fn relative_position(&self, t: f64) -> Vec3 {
+        self.orbit.map_or(Vec3::ZERO, |orbit| orbit.position_at(t))
+    }
+
+    /// Ángulo de giro sobre su eje en `t`, reducido a una vuelta antes de pasar a f32
+    pub fn rotation(&self, t: f64) -> f32 {
+        (self.spin as f64 * t).rem_euclid(std::f64::consts::TAU) as f32
+    }
+
+    /// Tiempo de la animación de sus shaders en `t`. Se reduce en f64 a un número entero de
+    /// vueltas de su órbita (cerca de `SHADER_TIME_WRAP`) antes de pasar a f32, para que no
+    /// pierda resolución por grande que sea `t`; la animación solo salta al volver a empezar.
+    pub fn shader_time(&self, t: f64) -> f32 {
+        let period = self.orbit.map_or(0.0, |orbit| orbit.period.abs() as f64);
+        let wrap = if period > 0.0 { period * (SHADER_TIME_WRAP / period).ceil() } else { SHADER_TIME_WRAP };
+        t.rem_euclid(wrap) as f32
+    }
+
+    /// Radio de la esfera que contiene al cuerpo y a sus anillos
+    pub fn extent(&self) -> f32 {
+        self.radius * self.rings.as_ref().map_or(1.0, RingSystem::outer_radius)
+    }
+}
+
+fn default_dwarf_planets() -> bool {
+    true
+}
+
+/// Contenido del archivo de escena: una tabla `[[body]]` por cuerpo
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SceneFile {
+    /// Incluir los cuerpos marcados como planeta enano
+    #[serde(default = "default_dwarf_planets")]
+    dwarf_planets: bool,
+    #[serde(rename = "body", default)]
+    bodies: Vec<Body>,
+}
+
+impl SceneFile {
+    /// Quita los planetas enanos y los satélites que cuelgan de ellos
+    fn without_dwarf_planets(self) -> Vec<Body> {
+        let mut removed: Vec<String> = Vec::new();
+        let mut bodies = Vec::with_capacity(self.bodies.len());
+        for body in self.bodies {
+            if body.dwarf || body.parent.as_ref().is_some_and(|parent| removed.contains(parent)) {
+                removed.push(body.name);
+            } else {
+                bodies.push(body);
+            }
+        }
+        bodies
+    }
+}
+
+/// Posición y matriz de modelo de un cuerpo en un instante
+#[derive(Debug, Clone, Copy)]
+pub struct BodyPlacement {
+    /// Centro del cuerpo en el mundo
+    pub position: Vec3,
+    /// Matriz de mundo de su malla, con el giro y la escala del cuerpo
+    pub model_matrix: Mat4,
+    /// Matriz de mundo de sus anillos, si tiene
+    pub ring_matrix: Option<Mat4>,
+}
+
+/// Nodos de un cuerpo en el grafo de escena. Los satélites cuelgan del nodo de la órbita,
+/// que solo traslada, para no heredar el giro ni la escala de su planeta.
+#[derive(Debug, Clone, Copy)]
+struct BodyNodes {
+    orbit: NodeId,
+    mesh: NodeId,
+    rings: Option<NodeId>,
+}
+
+/// Estado de todos los cuerpos en un instante de la simulación, en el orden de `SolarSystem::bodies`
+pub struct Snapshot {
+    pub bodies: Vec<BodyPlacement>,
+}
+
+pub struct SolarSystem {
+    pub max_r: f32,              // Radio de la esfera en espacio del modelo
+    pub model_center: Vec3,      // Traslación que centra la esfera en el origen
+    /// Cada padre aparece antes que sus satélites
+    pub bodies: Vec<Body>,
+    /// Índice del padre de cada cuerpo en `bodies`
+    parents: Vec<Option<usize>>,
+    /// Jerarquía Sol → planeta → luna en reposo (t = 0); `snapshot` la posa en cada instante
+    graph: SceneGraph,
+    nodes: Vec<BodyNodes>,
+    /// Malla de los anillos de cada cuerpo, en radios del cuerpo
+    ring_models: Vec<Option<tobj::Model>>,
+}
+
+impl Default for SolarSystem {
+    /// Escena vacía
+    fn default() -> Self {
+        SolarSystem {
+            max_r: SPHERE_MODEL_RADIUS,
+            model_center: Vec3::ZERO,
+            bodies: Vec::new(),
+            parents: Vec::new(),
+            graph: SceneGraph::new(),
+            nodes: Vec::new(),
+            ring_models: Vec::new(),
+        }
+    }
+}
+
+impl SolarSystem {
+    /// Lee la escena de un archivo TOML
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AssetError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => AssetError::MissingFile { path: path.to_path_buf() },
+            _ => AssetError::Io { path: path.to_path_buf(), source },
+        })?;
+        let parse_error = |message: String| AssetError::Parse { path: path.to_path_buf(), message };
+
+        let scene: SceneFile = toml::from_str(&content).map_err(|e| parse_error(e.to_string()))?;
+        let bodies = if scene.dwarf_planets { scene.bodies } else { scene.without_dwarf_planets() };
+        SolarSystem::from_bodies(bodies).map_err(parse_error)
+    }
+
+    /// Configura los cuerpos alrededor de la esfera procedural compartida, comprobando que
+    /// los nombres no se repitan, que cada padre esté definido antes que sus satélites y
+    /// que las órbitas sean elipses cerradas
+    pub fn from_bodies(bodies: Vec<Body>) -> Result<Self, String> {
+        let mut parents = Vec::with_capacity(bodies.len());
+        for (i, body) in bodies.iter().enumerate() {
+            if bodies[..i].iter().any(|other| other.name == body.name) {
+                return Err(format!("El cuerpo '{}' está repetido", body.name));
+            }
+            if body.radius <= 0.0 {
+                return Err(format!("El cuerpo '{}' necesita un radio positivo", body.name));
+            }
+            if body.mass.is_some_and(|mass| mass <= 0.0) {
+                return Err(format!("La masa de '{}' debe ser positiva", body.name));
+            }
+            if let Some(rings) = &body.rings {
+                rings.validate().map_err(|e| format!("Anillos de '{}': {}", body.name, e))?;
+            }
+            let parent = match &body.parent {
+                Some(parent) => Some(
+                    bodies[..i].iter().position(|other| &other.name == parent).ok_or_else(|| {
+                        format!("El padre '{}' de '{}' no está definido antes que él", parent, body.name)
+                    })?,
+                ),
+                None => None,
+            };
+            if let Some(orbit) = &body.orbit {
+                if parent.is_none() {
+                    return Err(format!("El cuerpo '{}' tiene órbita pero no padre", body.name));
+                }
+                orbit.validate().map_err(|e| format!("Órbita de '{}': {}", body.name, e))?;
+            }
+            if let Some(id) = &body.ephemeris {
+                if body.orbit.is_none() {
+                    return Err(format!("El cuerpo '{}' tiene efemérides pero no órbita", body.name));
+                }
+                if !ephemeris::is_known(id) {
+                    return Err(format!("El cuerpo '{}' usa las efemérides desconocidas '{}'", body.name, id));
+                }
+            }
+            parents.push(parent);
+        }
+
+        let mut system = SolarSystem { bodies, parents, ..SolarSystem::default() };
+        for i in 0..system.bodies.len() {
+            let parent_orbit = system.parents[i].map(|parent| system.nodes[parent].orbit);
+            let body = &system.bodies[i];
+            let orbit = system.graph.add(parent_orbit, Transform::default());
+            let mesh = system.graph.add(Some(orbit), Transform {
+                center: system.model_center,
+                scale: system.scale_for(body.radius),
+                ..Transform::default()
+            });
+            // La malla de los anillos está en radios del cuerpo
+            let rings = body.rings.as_ref().map(|_| {
+                system.graph.add(Some(orbit), Transform { scale: body.radius, ..Transform::default() })
+            });
+            let ring_model = body.rings.as_ref().map(|rings| {
+                tobj::Model::new(rings.mesh(), format!("rings_{}", body.name))
+            });
+            system.nodes.push(BodyNodes { orbit, mesh, rings });
+            system.ring_models.push(ring_model);
+        }
+        Ok(system)
+    }
+
+    /// Comprueba que todos los cuerpos usen un preset de shader existente y sus anillos uno
+    /// de tipo `ring`
+    pub fn check_shaders(&self, shaders: &ShaderPresets) -> Result<(), String> {
+        for body in &self.bodies {
+            if shaders.get(&body.shader).is_none() {
+                return Err(format!("El cuerpo '{}' usa el preset de shader desconocido '{}'", body.name, body.shader));
+            }
+            if let Some(rings) = &body.rings {
+                if shaders.ring(&rings.shader).is_none() {
+                    return Err(format!(
+                        "Los anillos de '{}' usan '{}', que no es un preset de tipo ring", body.name, rings.shader
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Índice en `bodies` del padre del cuerpo `index`
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        self.parents[index]
+    }
+
+    /// Escala de la esfera compartida para un cuerpo de radio `radius`
+    pub fn scale_for(&self, radius: f32) -> f32 {
+        if self.max_r > 0.0 { radius / self.max_r } else { 1.0 }
+    }
+
+    /// Calcula la posición de cada cuerpo en el tiempo simulado `t` (segundos)
+    pub fn snapshot(&self, t: f64) -> Snapshot {
+        self.snapshot_with(t, &[])
+    }
+
+    /// Como `snapshot`, pero los cuerpos con una posición en `positions` (coordenadas del
+    /// mundo, por índice de `bodies`) la usan en lugar de su órbita; sus satélites los siguen
+    pub fn snapshot_with(&self, t: f64, positions: &[Option<Vec3>]) -> Snapshot {
+        let mut graph = self.graph.clone();
+        let mut world_positions: Vec<Vec3> = Vec::with_capacity(self.bodies.len());
+        for (i, (body, (nodes, parent))) in self.bodies.iter().zip(self.nodes.iter().zip(&self.parents)).enumerate() {
+            let parent_position = parent.map(|parent| world_positions[parent]);
+            // Sin padre el cuerpo queda fijo en el origen
+            let relative = match (positions.get(i).copied().flatten(), parent_position) {
+                (Some(position), parent_position) => position - parent_position.unwrap_or(Vec3::ZERO),
+                (None, Some(_)) => body.relative_position(t),
+                (None, None) => Vec3::ZERO,
+            };
+            world_positions.push(parent_position.unwrap_or(Vec3::ZERO) + relative);
+            graph.set_local(nodes.orbit, Transform::from_translation(relative));
+            let mesh = Transform { rotation: Vec3::new(0.0, body.rotation(t), 0.0), ..*graph.local(nodes.mesh) };
+            graph.set_local(nodes.mesh, mesh);
+        }
+        graph.update();
+
+        let bodies = self
+            .nodes
+            .iter()
+            .map(|nodes| BodyPlacement {
+                position: graph.world_position(nodes.orbit),
+                model_matrix: graph.world_matrix(nodes.mesh),
+                ring_matrix: nodes.rings.map(|rings| graph.world_matrix(rings)),
+            })
+            .collect();
+        Snapshot { bodies }
+    }
+
+    /// Comprueba si una esfera de radio `radius` en `point` choca con algún cuerpo
+    pub fn collides(&self, snapshot: &Snapshot, point: Vec3, radius: f32) -> bool {
+        self.bodies.iter().zip(&snapshot.bodies).any(|(body, placement)| {
+            (point - placement.position).length() < body.radius + radius
+        })
+    }
+}
+
+/// Genera el campo de estrellas (skybox procedural) con una semilla fija.
+/// Las coordenadas están normalizadas en [0, 1) para sobrevivir a cambios de resolución.
+pub fn generate_stars() -> Vec<(f32, f32, u8)> {
+    const STAR_COUNT: usize = 800;
+    let mut rng = StdRng::seed_from_u64(0xC0FFEE);
+    let mut stars: Vec<(f32, f32, u8)> = Vec::with_capacity(STAR_COUNT);
+    for _ in 0..STAR_COUNT {
+        let x = rng.gen_range(0.0..1.0);
+        let y = rng.gen_range(0.0..1.0);
+        let b = rng.gen_range(120..256) as u8; // brillo
+        stars.push((x, y, b));
+    }
+    stars
+}
+
+/// Dibuja la elipse de una órbita proyectada en pantalla
+fn draw_orbit(context: &mut RenderContext, view_projection: Mat4, orbit: &OrbitalElements, center: Vec3, col: Color) {
+    let width = context.width() as i32;
+    let height = context.height() as i32;
+    let segments = 128usize;
+    let mut prev: Option<(i32,i32)> = None;
+    for i in 0..=segments {
+        // Muestreo uniforme en anomalía excéntrica: más denso cerca de los extremos del eje mayor
+        let eccentric_anomaly = i as f32 / segments as f32 * std::f32::consts::TAU;
+        let world_point = center + orbit.position_at_eccentric_anomaly(eccentric_anomaly);
+        let p = view_projection * world_point.extend(1.0);
+        // Skip extreme / behind-camera projections to avoid giant lines when very close
+        if p.w.abs() < 1e-4 { prev = None; continue; }
+        let nx = p.x / p.w;
+        let ny = p.y / p.w;
+        // If normalized coords are absurdly large, skip to avoid artifacts
+        if nx.abs() > 100.0 || ny.abs() > 100.0 { prev = None; continue; }
+        // Map to screen
+        let (sx, sy) = context.ndc_to_screen(nx, ny);
+        let (sx, sy) = (sx as i32, sy as i32);
+        if let Some((px, py)) = prev {
+            // Only draw if both points are reasonably on/near screen bounds
+            let near_screen = |x: i32, y: i32| {
+                (-200..=width + 200).contains(&x) && (-200..=height + 200).contains(&y)
+            };
+            if near_screen(px, py) && near_screen(sx, sy) {
+                context.framebuffer.draw_line(px, py, sx, sy, col);
+            }
+        }
+        prev = Some((sx, sy));
+    }
+}
+
+/// Anillos de un cuerpo pendientes de dibujar
+struct RingDraw<'a> {
+    /// Distancia del cuerpo a la cámara
+    distance: f32,
+    model: &'a tobj::Model,
+    matrix: Mat4,
+    time: f32,
+    shader: Ring,
+    /// Esfera del cuerpo, que hace sombra sobre los anillos
+    center: Vec3,
+    radius: f32,
+}
+
+/// Anillos visibles que `render_scene` deja para el final: se mezclan con lo que ya hay en
+/// el framebuffer, así que van después de toda la geometría opaca, también la que se dibuje
+/// fuera de la escena (como la nave)
+#[must_use = "los anillos solo se dibujan al llamar a `render`"]
+pub struct TransparentPass<'a> {
+    rings: Vec<RingDraw<'a>>,
+}
+
+impl TransparentPass<'_> {
+    /// Dibuja los anillos de atrás hacia delante, en la sombra de su cuerpo y visibles por
+    /// las dos caras
+    pub fn render(mut self, context: &mut RenderContext) {
+        self.rings.sort_by(|a, b| b.distance.total_cmp(&a.distance));
+        context.backface_culling = false;
+        for ring in &self.rings {
+            let shadowed = SphereShadow { shader: &ring.shader, center: ring.center, radius: ring.radius };
+            render_with_matrix(context, ring.model, ring.matrix, &shadowed, ring.time);
+        }
+        context.backface_culling = true;
+    }
+}
+
+/// Dibuja estrellas, órbitas y todos los cuerpos celestes con la cámara ya fijada
+/// en el contexto. Es el mismo camino de render para el modo interactivo y el modo sin ventana.
+/// Los anillos quedan en el `TransparentPass` devuelto, que se dibuja al final.
+pub fn render_scene<'a>(
+    context: &mut RenderContext,
+    sphere_lod: &SphereLod,
+    shaders: &'a ShaderPresets,
+    system: &'a SolarSystem,
+    snapshot: &Snapshot,
+    stars: &[(f32, f32, u8)],
+    time: f64,
+) -> TransparentPass<'a> {
+    // Limpia la pantalla con color negro (espacio) y el z-buffer compartido
+    context.clear(Color::RGB(0, 0, 0));
+
+    // Dibujar skybox procedural: estrellas
+    let (width, height) = (context.width() as f32, context.height() as f32);
+    for (x, y, b) in stars.iter() {
+        context.framebuffer.set_pixel((x * width) as i32, (y * height) as i32, Color::RGB(*b, *b, *b));
+    }
+
+    // Dibujar órbitas proyectadas en pantalla
+    let view_projection = context.view_projection();
+
+    // Órbita de cada cuerpo alrededor de la posición actual de su padre
+    for (body, parent) in system.bodies.iter().zip(&system.parents) {
+        if let (Some(orbit), Some(parent)) = (&body.orbit, parent) {
+            let [r, g, b] = body.orbit_color;
+            let center = snapshot.bodies[*parent].position;
+            draw_orbit(context, view_projection, orbit, center, Color::RGB(r, g, b));
+        }
+    }
+
+    // Anillos visibles con su distancia a la cámara, para dibujarlos al final
+    let mut rings = Vec::new();
+
+    // Todos los cuerpos usan la misma geometría y centrado; cada uno se colorea con su preset
+    for (i, (body, placement)) in system.bodies.iter().zip(&snapshot.bodies).enumerate() {
+        let Some(shader) = shaders.get(&body.shader) else { continue };
+        let position = placement.position;
+        let time = body.shader_time(time);
+        // Cuerpos completamente fuera de cámara (anillos incluidos) no llegan a transformarse
+        if context.cull_sphere(position, body.extent()) { continue; }
+
+        let body_rings = body.rings.as_ref().zip(system.ring_models[i].as_ref()).zip(placement.ring_matrix);
+        let body_rings = body_rings.and_then(|((rings, model), matrix)| {
+            Some((rings, model, matrix, rings.shader(shaders.ring(&rings.shader)?)))
+        });
+        let ring_shadow = body_rings.as_ref().map(|(rings, _, matrix, ring_shader)| {
+            RingShadow::new(shader, rings, ring_shader, *matrix)
+        });
+        let shader: &dyn Shader = match &ring_shadow {
+            Some(ring_shadow) => ring_shadow,
+            None => shader,
+        };
+
+        // Resolución según el tamaño del cuerpo en pantalla
+        let models = sphere_lod.select(context.projected_radius(position, body.radius));
+        for model in models.iter() {
+            render_with_matrix(context, model, placement.model_matrix, shader, time);
+        }
+
+        if let Some((_, model, matrix, ring_shader)) = body_rings {
+            let distance = (position - context.camera_position()).length();
+            rings.push(RingDraw { distance, model, matrix, time, shader: ring_shader, center: position, radius: body.radius });
+        }
+    }
+
+    TransparentPass { rings }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn default_scene() -> SolarSystem {
+        SolarSystem::load(SCENE_PATH).unwrap()
+    }
+
+    fn body<'a>(system: &'a SolarSystem, name: &str) -> &'a Body {
+        system.bodies.iter().find(|body| body.name == name).unwrap()
+    }
+
+    #[test]
+    fn default_scene_keeps_original_start_positions() {
+        // Radio y ángulo iniciales de las órbitas circulares originales (x = r·cos, z = r·sin)
+        let system = default_scene();
+        for (name, radius, angle) in [("venus", 99.0, 0.5 * PI), ("jupiter", 247.5, 1.5 * PI)] {
+            let body = body(&system, name);
+            let orbit = body.orbit.unwrap();
+            let expected = Vec3::new(radius * angle.cos(), 0.0, radius * angle.sin());
+            // La excentricidad aleja al cuerpo del círculo como mucho unos 2·e·a
+            let tolerance = 2.0 * orbit.eccentricity * orbit.semi_major_axis + 1.0;
+            let position = body.relative_position(0.0);
+            assert!(
+                (position - expected).length() < tolerance,
+                "{}: {} frente a {}", name, position, expected
+            );
+        }
+    }
+
+    #[test]
+    fn adjacent_orbits_do_not_overlap() {
+        // Cada cuerpo que orbita el Sol barre una corona entre su perihelio y su afelio,
+        // ensanchada con su radio, sus anillos y las órbitas de sus satélites
+        let system = default_scene();
+        let reach = |i: usize| {
+            (0..system.bodies.len())
+                .filter(|&j| system.parent(j) == Some(i))
+                .map(|j| {
+                    let satellite = &system.bodies[j];
+                    let apoapsis = satellite.orbit.map_or(0.0, |orbit| orbit.semi_major_axis * (1.0 + orbit.eccentricity));
+                    apoapsis + satellite.extent()
+                })
+                .fold(system.bodies[i].extent(), f32::max)
+        };
+        let mut bands: Vec<(f32, f32, &str)> = (0..system.bodies.len())
+            .filter(|&i| system.parent(i).is_some_and(|parent| system.parent(parent).is_none()))
+            .filter_map(|i| {
+                let body = &system.bodies[i];
+                let orbit = body.orbit?;
+                let a = orbit.semi_major_axis;
+                let e = orbit.eccentricity;
+                Some((a * (1.0 - e) - reach(i), a * (1.0 + e) + reach(i), body.name.as_str()))
+            })
+            .collect();
+        bands.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        for pair in bands.windows(2) {
+            let (inner, outer) = (pair[0], pair[1]);
+            assert!(
+                inner.1 < outer.0,
+                "{} llega hasta {} y {} empieza en {}", inner.2, inner.1, outer.2, outer.0
+            );
+        }
+    }
+
+    #[test]
+    fn shader_time_keeps_resolution() {
+        // Media hora a x10000: en f32 el tiempo ya solo avanzaría a saltos de ~1 s
+        let system = default_scene();
+        let mercury = body(&system, "mercurio");
+        let t = 1.6e7;
+        let delta = mercury.shader_time(t + 0.1) - mercury.shader_time(t);
+        assert!((delta - 0.1).abs() < 1e-3, "avanzó {}", delta);
+    }
+}
@@ -0,0 +1,99 @@
+use glam::{Mat4, Vec3};
+use crate::utils::create_model_matrix;
+
+/// Transformación de un nodo relativa a su padre, con los mismos parámetros que
+/// `create_model_matrix`
+#[derive(Debug, Clone, Copy)]
+pub struct Transform {
+    pub translation: Vec3,
+    /// Traslación previa a la escala que centra la malla en el origen del nodo
+    pub center: Vec3,
+    pub scale: f32,
+    /// Ángulos de Euler en radianes (se aplican X, luego Y, luego Z)
+    pub rotation: Vec3,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform {
+            translation: Vec3::ZERO,
+            center: Vec3::ZERO,
+            scale: 1.0,
+            rotation: Vec3::ZERO,
+        }
+    }
+}
+
+impl Transform {
+    pub fn from_translation(translation: Vec3) -> Self {
+        Transform { translation, ..Transform::default() }
+    }
+
+    pub fn matrix(&self) -> Mat4 {
+        create_model_matrix(self.translation, self.center, self.scale, self.rotation)
+    }
+}
+
+/// Identificador de un nodo dentro de su grafo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone)]
+struct SceneNode {
+    parent: Option<NodeId>,
+    local: Transform,
+    world: Mat4,
+}
+
+/// Jerarquía de transformaciones: la matriz de mundo de cada nodo es la de su padre por
+/// su transformación local. Los nodos se guardan en orden de creación y un padre siempre
+/// se crea antes que sus hijos, así que basta un recorrido lineal para actualizarlos.
+#[derive(Debug, Clone, Default)]
+pub struct SceneGraph {
+    nodes: Vec<SceneNode>,
+}
+
+impl SceneGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Añade un nodo colgado de `parent` (o en la raíz del mundo)
+    pub fn add(&mut self, parent: Option<NodeId>, local: Transform) -> NodeId {
+        let world = match parent {
+            Some(parent) => self.nodes[parent.0].world * local.matrix(),
+            None => local.matrix(),
+        };
+        self.nodes.push(SceneNode { parent, local, world });
+        NodeId(self.nodes.len() - 1)
+    }
+
+    pub fn local(&self, id: NodeId) -> &Transform {
+        &self.nodes[id.0].local
+    }
+
+    /// Cambia la transformación local; las matrices de mundo se recalculan con `update`
+    pub fn set_local(&mut self, id: NodeId, local: Transform) {
+        self.nodes[id.0].local = local;
+    }
+
+    /// Recalcula las matrices de mundo de todos los nodos
+    pub fn update(&mut self) {
+        for i in 0..self.nodes.len() {
+            let local = self.nodes[i].local.matrix();
+            self.nodes[i].world = match self.nodes[i].parent {
+                Some(parent) => self.nodes[parent.0].world * local,
+                None => local,
+            };
+        }
+    }
+
+    pub fn world_matrix(&self, id: NodeId) -> Mat4 {
+        self.nodes[id.0].world
+    }
+
+    /// Origen del nodo en coordenadas del mundo
+    pub fn world_position(&self, id: NodeId) -> Vec3 {
+        self.nodes[id.0].world.w_axis.truncate()
+    }
+}
@@ -0,0 +1,116 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Júpiter - gigante gaseoso con bandas
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Jupiter {
+    /// Número de bandas de latitud
+    pub band_freq: f32,
+    /// Colores RGB 0-255 de las zonas claras y los cinturones oscuros
+    pub zone_color: [f32; 3],
+    pub belt_color: [f32; 3],
+    /// Latitud normalizada (0 = norte, 1 = sur) de la Gran Mancha Roja
+    pub spot_latitude: f32,
+    pub spot_color: [f32; 3],
+    /// Color que suma la atmósfera en el borde del disco
+    pub atmosphere_color: [f32; 3],
+}
+
+impl Default for Jupiter {
+    fn default() -> Self {
+        Jupiter {
+            band_freq: 12.0,
+            zone_color: [220.0, 190.0, 140.0],
+            belt_color: [180.0, 130.0, 80.0],
+            spot_latitude: 0.6,
+            spot_color: [200.0, 100.0, 80.0],
+            atmosphere_color: [80.0, 70.0, 50.0],
+        }
+    }
+}
+
+impl Shader for Jupiter {
+    // Las comparaciones de latitud son las del shader original
+    #[allow(clippy::manual_range_contains)]
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, intensity, time, .. } = *fragment;
+        let phi = position.x.atan2(position.z) + time * 0.1;
+        let theta = (position.y / position.length()).acos();
+        let lat = theta / std::f32::consts::PI;
+        
+        let band_freq = self.band_freq;
+        let band_pattern = (lat * band_freq).sin();
+        let band_noise = noise(phi * 2.0, lat * 15.0 + time * 0.05);
+        
+        let mut base_r;
+        let mut base_g;
+        let mut base_b;
+        
+        if (band_pattern + band_noise * 0.3) > 0.0 {
+            base_r = self.zone_color[0] + band_noise * 20.0;
+            base_g = self.zone_color[1] + band_noise * 20.0;
+            base_b = self.zone_color[2] + band_noise * 15.0;
+        } else {
+            base_r = self.belt_color[0] + band_noise * 15.0;
+            base_g = self.belt_color[1] + band_noise * 15.0;
+            base_b = self.belt_color[2] + band_noise * 10.0;
+        }
+        
+        let turb_noise1 = noise(phi * 8.0 + time * 0.2, lat * 20.0);
+        let turb_noise2 = noise(phi * 15.0 - time * 0.15, lat * 30.0 + 100.0);
+        let turbulence = turb_noise1 * 0.6 + turb_noise2 * 0.4;
+        
+        base_r += turbulence * 30.0 - 15.0;
+        base_g += turbulence * 25.0 - 12.0;
+        base_b += turbulence * 20.0 - 10.0;
+        
+        let spot_lat_center = self.spot_latitude;
+        let spot_lon_center = std::f32::consts::PI * 0.5 + time * 0.02;
+        
+        let lat_diff = (lat - spot_lat_center).abs();
+        let lon_diff = (phi - spot_lon_center).abs().min(std::f32::consts::TAU - (phi - spot_lon_center).abs());
+        
+        let spot_distance = (lat_diff * lat_diff * 400.0 + lon_diff * lon_diff * 100.0).sqrt();
+        
+        if spot_distance < 1.5 {
+            let spot_factor = (1.0 - spot_distance / 1.5).max(0.0);
+            let spot_noise = noise(phi * 10.0 + time * 0.1, lat * 10.0);
+            
+            base_r = base_r * (1.0 - spot_factor * 0.8) + (self.spot_color[0] + spot_noise * 20.0) * spot_factor * 0.8;
+            base_g = base_g * (1.0 - spot_factor * 0.8) + (self.spot_color[1] + spot_noise * 10.0) * spot_factor * 0.8;
+            base_b = base_b * (1.0 - spot_factor * 0.8) + (self.spot_color[2] + spot_noise * 10.0) * spot_factor * 0.8;
+        }
+        
+        let polar_factor = if lat < 0.15 || lat > 0.85 {
+            let dist = lat.min(1.0 - lat);
+            (0.15 - dist) / 0.15
+        } else {
+            0.0
+        };
+        
+        if polar_factor > 0.0 {
+            base_r += polar_factor * 30.0;
+            base_g += polar_factor * 40.0;
+            base_b += polar_factor * 60.0;
+        }
+        
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        let atmosphere = edge_factor * edge_factor * 0.2;
+        
+        base_r += atmosphere * self.atmosphere_color[0];
+        base_g += atmosphere * self.atmosphere_color[1];
+        base_b += atmosphere * self.atmosphere_color[2];
+        
+        let enhanced_intensity = intensity * 0.4 + 0.6;
+        
+        let r = (base_r * enhanced_intensity).min(255.0);
+        let g = (base_g * enhanced_intensity).min(255.0);
+        let b = (base_b * enhanced_intensity).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+}
@@ -0,0 +1,100 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Planeta Marte - rojo/oxidado
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Mars {
+    /// Colores RGB 0-255 de las llanuras oscuras, el suelo oxidado y las zonas claras
+    pub dark_color: [f32; 3],
+    pub mid_color: [f32; 3],
+    pub light_color: [f32; 3],
+    /// Distancia normalizada al ecuador (0.5 = polo) donde empiezan los casquetes
+    pub ice_cap_threshold: f32,
+    /// Umbral del ruido a partir del cual hay tormentas de polvo
+    pub dust_threshold: f32,
+    pub dust_color: [f32; 3],
+    /// Color que suma la atmósfera en el borde del disco
+    pub atmosphere_color: [f32; 3],
+}
+
+impl Default for Mars {
+    fn default() -> Self {
+        Mars {
+            dark_color: [80.0, 40.0, 30.0],
+            mid_color: [193.0, 68.0, 14.0],
+            light_color: [210.0, 105.0, 30.0],
+            ice_cap_threshold: 0.85,
+            dust_threshold: 0.75,
+            dust_color: [220.0, 180.0, 120.0],
+            atmosphere_color: [100.0, 30.0, 10.0],
+        }
+    }
+}
+
+impl Shader for Mars {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, intensity, time, .. } = *fragment;
+        let phi = position.x.atan2(position.z) + time * 0.05;
+        let theta = (position.y / position.length()).acos();
+        
+        let terrain_noise1 = noise(phi * 4.0, theta * 4.0);
+        let terrain_noise2 = noise(phi * 8.0 + 100.0, theta * 8.0 + 100.0);
+        let terrain_combined = terrain_noise1 * 0.6 + terrain_noise2 * 0.4;
+        
+        let [mut base_r, mut base_g, mut base_b] = if terrain_combined < 0.3 {
+            self.dark_color
+        } else if terrain_combined < 0.7 {
+            self.mid_color
+        } else {
+            self.light_color
+        };
+        
+        let polar_threshold = self.ice_cap_threshold;
+        let polar_distance = theta.min(std::f32::consts::PI - theta) / std::f32::consts::PI;
+        
+        if polar_distance > polar_threshold {
+            let polar_factor = ((polar_distance - polar_threshold) / (1.0 - polar_threshold)).min(1.0);
+            let ice_white = 240.0;
+            let ice_cream = 230.0;
+            base_r = base_r * (1.0 - polar_factor) + ice_white * polar_factor;
+            base_g = base_g * (1.0 - polar_factor) + ice_cream * polar_factor;
+            base_b = base_b * (1.0 - polar_factor) + (ice_cream * 0.9) * polar_factor;
+        }
+        
+        let dust_noise1 = noise(phi * 3.0 + time * 0.1, theta * 3.0);
+        let dust_noise2 = noise(phi * 6.0 - time * 0.05, theta * 6.0 + 200.0);
+        let dust_combined = dust_noise1 * 0.5 + dust_noise2 * 0.5;
+        
+        let dust_factor = if dust_combined > self.dust_threshold {
+            ((dust_combined - self.dust_threshold) / (1.0 - self.dust_threshold)).min(1.0) * 0.4
+        } else {
+            0.0
+        };
+        
+        if dust_factor > 0.0 {
+            let [dust_yellow_r, dust_yellow_g, dust_yellow_b] = self.dust_color;
+            base_r = base_r * (1.0 - dust_factor) + dust_yellow_r * dust_factor;
+            base_g = base_g * (1.0 - dust_factor) + dust_yellow_g * dust_factor;
+            base_b = base_b * (1.0 - dust_factor) + dust_yellow_b * dust_factor;
+        }
+        
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        let atmosphere = edge_factor * edge_factor * 0.15;
+        
+        base_r += atmosphere * self.atmosphere_color[0];
+        base_g += atmosphere * self.atmosphere_color[1];
+        base_b += atmosphere * self.atmosphere_color[2];
+        
+        let enhanced_intensity = intensity * 0.5 + 0.5;
+        
+        let r = (base_r * enhanced_intensity).min(255.0);
+        let g = (base_g * enhanced_intensity).min(255.0);
+        let b = (base_b * enhanced_intensity).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+}
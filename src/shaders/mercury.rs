@@ -0,0 +1,76 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Mercurio - roca gris parda saturada de cráteres, con cráteres jóvenes de rayos brillantes
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Mercury {
+    /// Colores RGB 0-255 de las llanuras oscuras, el terreno medio y las zonas claras
+    pub dark_color: [f32; 3],
+    pub mid_color: [f32; 3],
+    pub light_color: [f32; 3],
+    /// Frecuencia del ruido de los cráteres grandes (los medianos y pequeños usan 2x y 4x)
+    pub crater_freq: f32,
+    /// Umbral del ruido a partir del cual hay cráter
+    pub crater_threshold: f32,
+    /// Umbral del ruido de los sistemas de rayos claros
+    pub ray_threshold: f32,
+}
+
+impl Default for Mercury {
+    fn default() -> Self {
+        Mercury {
+            dark_color: [95.0, 88.0, 82.0],
+            mid_color: [140.0, 130.0, 120.0],
+            light_color: [178.0, 168.0, 155.0],
+            crater_freq: 20.0,
+            crater_threshold: 0.7,
+            ray_threshold: 0.8,
+        }
+    }
+}
+
+impl Shader for Mercury {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, intensity, .. } = *fragment;
+        let phi = position.x.atan2(position.z);
+        let theta = (position.y / position.length()).acos();
+
+        // Llanuras volcánicas lisas y terreno antiguo más claro
+        let terrain = noise(phi * 4.0, theta * 4.0) * 0.5
+            + noise(phi * 9.0 + 50.0, theta * 9.0 + 50.0) * 0.3
+            + noise(phi * 20.0 + 10.0, theta * 20.0) * 0.2;
+        let mut base = if terrain < 0.5 {
+            Vec3::from(self.dark_color).lerp(Vec3::from(self.mid_color), terrain / 0.5)
+        } else {
+            Vec3::from(self.mid_color).lerp(Vec3::from(self.light_color), (terrain - 0.5) / 0.5)
+        };
+
+        // Tres tamaños de cráter: la superficie está saturada
+        for (scale, offset, weight) in [(1.0, 0.0, 0.45), (2.0, 300.0, 0.3), (4.0, 600.0, 0.2)] {
+            let freq = self.crater_freq * scale;
+            let crater = noise(phi * freq + offset, theta * freq + offset);
+            if crater > self.crater_threshold {
+                let depth = (crater - self.crater_threshold) / (1.0 - self.crater_threshold);
+                // Borde claro y fondo oscuro
+                let shade = if depth < 0.25 { 0.25 * weight } else { -depth * weight };
+                base += Vec3::new(90.0, 85.0, 80.0) * shade;
+            }
+        }
+
+        let ray = noise(phi * 5.0 + theta * 11.0, theta * 3.0 + 70.0);
+        if ray > self.ray_threshold {
+            let brightness = (ray - self.ray_threshold) / (1.0 - self.ray_threshold) * 0.35;
+            base += Vec3::new(110.0, 105.0, 100.0) * brightness;
+        }
+
+        // Sin atmósfera: el terminador es abrupto y la cara nocturna casi negra
+        let harsh_intensity = if intensity > 0.05 { intensity * 0.9 + 0.1 } else { intensity * 2.0 };
+
+        let color = (base * harsh_intensity).clamp(Vec3::ZERO, Vec3::splat(255.0));
+        Color::RGB(color.x as u8, color.y as u8, color.z as u8)
+    }
+}
@@ -0,0 +1,85 @@
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Luna de la Tierra - gris rocoso con cráteres
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Moon {
+    /// Colores RGB 0-255 de los mares, las llanuras y las tierras altas
+    pub dark_color: [f32; 3],
+    pub mid_color: [f32; 3],
+    pub light_color: [f32; 3],
+    /// Frecuencia del ruido de los cráteres grandes (los pequeños usan el doble)
+    pub crater_freq: f32,
+    /// Umbral del ruido a partir del cual hay cráter grande
+    pub crater_threshold: f32,
+}
+
+impl Default for Moon {
+    fn default() -> Self {
+        Moon {
+            dark_color: [80.0, 80.0, 85.0],
+            mid_color: [140.0, 140.0, 145.0],
+            light_color: [180.0, 180.0, 185.0],
+            crater_freq: 15.0,
+            crater_threshold: 0.75,
+        }
+    }
+}
+
+impl Shader for Moon {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, intensity, .. } = *fragment;
+        let phi = position.x.atan2(position.z);
+        let theta = (position.y / position.length()).acos();
+        
+        let terrain_noise1 = noise(phi * 6.0, theta * 6.0);
+        let terrain_noise2 = noise(phi * 12.0 + 100.0, theta * 12.0 + 100.0);
+        let terrain_combined = terrain_noise1 * 0.6 + terrain_noise2 * 0.4;
+        
+        let [mut base_r, mut base_g, mut base_b] = if terrain_combined < 0.3 {
+            self.dark_color
+        } else if terrain_combined < 0.7 {
+            self.mid_color
+        } else {
+            self.light_color
+        };
+        
+        let crater_noise1 = noise(phi * self.crater_freq, theta * self.crater_freq);
+        let crater_noise2 = noise(phi * self.crater_freq * 2.0 + 200.0, theta * self.crater_freq * 2.0 + 200.0);
+        
+        if crater_noise1 > self.crater_threshold {
+            let crater_depth = (crater_noise1 - self.crater_threshold) / (1.0 - self.crater_threshold);
+            let crater_darken = crater_depth * 0.4;
+            base_r -= crater_darken * 80.0;
+            base_g -= crater_darken * 80.0;
+            base_b -= crater_darken * 85.0;
+        }
+        
+        if crater_noise2 > 0.8 {
+            let crater_depth = (crater_noise2 - 0.8) / 0.2;
+            let crater_darken = crater_depth * 0.3;
+            base_r -= crater_darken * 60.0;
+            base_g -= crater_darken * 60.0;
+            base_b -= crater_darken * 65.0;
+        }
+        
+        let ray_noise = noise(phi * 8.0 + theta * 8.0, theta * 4.0);
+        if ray_noise > 0.7 {
+            let ray_brightness = ((ray_noise - 0.7) / 0.3) * 0.2;
+            base_r += ray_brightness * 100.0;
+            base_g += ray_brightness * 100.0;
+            base_b += ray_brightness * 105.0;
+        }
+        
+        let harsh_intensity = if intensity > 0.5 { intensity * 0.8 + 0.2 } else { intensity * 0.3 };
+        
+        let r = (base_r * harsh_intensity).min(255.0);
+        let g = (base_g * harsh_intensity).min(255.0);
+        let b = (base_b * harsh_intensity).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+}
@@ -0,0 +1,94 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Neptuno - gigante de hielo azul oscuro
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Neptune {
+    /// Color RGB 0-255 de la atmósfera
+    pub base_color: [f32; 3],
+    /// Número de bandas de latitud
+    pub band_freq: f32,
+    /// Latitud normalizada (0 = norte, 1 = sur) de la Gran Mancha Oscura
+    pub spot_latitude: f32,
+    /// Umbral del ruido a partir del cual hay nubes brillantes
+    pub cloud_threshold: f32,
+    /// Color que suma la atmósfera en el borde del disco
+    pub atmosphere_color: [f32; 3],
+}
+
+impl Default for Neptune {
+    fn default() -> Self {
+        Neptune {
+            base_color: [40.0, 90.0, 200.0],
+            band_freq: 8.0,
+            spot_latitude: 0.4,
+            cloud_threshold: 0.8,
+            atmosphere_color: [40.0, 70.0, 100.0],
+        }
+    }
+}
+
+impl Shader for Neptune {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, intensity, time, .. } = *fragment;
+        let phi = position.x.atan2(position.z) + time * 0.12;
+        let theta = (position.y / position.length()).acos();
+        
+        let base_noise = noise(phi * 4.0, theta * 4.0 + time * 0.06);
+        
+        let mut base_r = self.base_color[0] + base_noise * 25.0;
+        let mut base_g = self.base_color[1] + base_noise * 30.0;
+        let mut base_b = self.base_color[2] + base_noise * 35.0;
+        
+        let lat = theta / std::f32::consts::PI;
+        let band_pattern = (lat * self.band_freq + time * 0.1).sin();
+        let band_noise = noise(phi * 3.0, lat * 12.0);
+        
+        let band_factor = (band_pattern * 0.5 + 0.5) * (band_noise * 0.5 + 0.5) * 0.2;
+        base_r += band_factor * 30.0 - 15.0;
+        base_g += band_factor * 25.0 - 12.0;
+        base_b += band_factor * 20.0 - 10.0;
+        
+        let spot_lat_center = self.spot_latitude;
+        let spot_lon_center = std::f32::consts::PI * 0.7 + time * 0.03;
+        
+        let lat_diff = (lat - spot_lat_center).abs();
+        let lon_diff = (phi - spot_lon_center).abs().min(std::f32::consts::TAU - (phi - spot_lon_center).abs());
+        
+        let spot_distance = (lat_diff * lat_diff * 300.0 + lon_diff * lon_diff * 80.0).sqrt();
+        
+        if spot_distance < 1.2 {
+            let spot_factor = (1.0 - spot_distance / 1.2).max(0.0) * 0.6;
+            base_r *= 1.0 - spot_factor * 0.5;
+            base_g *= 1.0 - spot_factor * 0.4;
+            base_b *= 1.0 - spot_factor * 0.3;
+        }
+        
+        let cloud_noise = noise(phi * 10.0 + time * 0.2, theta * 10.0);
+        if cloud_noise > self.cloud_threshold {
+            let cloud_factor = (cloud_noise - self.cloud_threshold) / (1.0 - self.cloud_threshold) * 0.3;
+            base_r += cloud_factor * 80.0;
+            base_g += cloud_factor * 100.0;
+            base_b += cloud_factor * 120.0;
+        }
+        
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        let atmosphere = edge_factor * edge_factor * 0.25;
+        
+        base_r += atmosphere * self.atmosphere_color[0];
+        base_g += atmosphere * self.atmosphere_color[1];
+        base_b += atmosphere * self.atmosphere_color[2];
+        
+        let enhanced_intensity = intensity * 0.3 + 0.7;
+        
+        let r = (base_r * enhanced_intensity).min(255.0);
+        let g = (base_g * enhanced_intensity).min(255.0);
+        let b = (base_b * enhanced_intensity).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+}
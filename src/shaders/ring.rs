@@ -0,0 +1,94 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Anillos planetarios: partículas de hielo en bandas concéntricas. La malla está en radios
+/// del planeta, así que la distancia del fragmento al eje es directamente ese radio. Es un
+/// shader transparente: la opacidad sale de un perfil de densidad radial. Los radios del
+/// degradado no se leen del preset: los pone `RingSystem::shader` con las bandas del cuerpo.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Ring {
+    /// Radios (en radios del planeta) entre los que va el degradado de color
+    #[serde(skip)]
+    pub inner_radius: f32,
+    #[serde(skip)]
+    pub outer_radius: f32,
+    /// Colores RGB 0-255 del borde interior y del exterior
+    pub inner_color: [f32; 3],
+    pub outer_color: [f32; 3],
+    /// Frecuencia radial de los anillos finos y cuánto oscurecen (0-1)
+    pub ringlet_freq: f32,
+    pub ringlet_contrast: f32,
+    /// Opacidad (0-1) de la parte más densa vista de frente
+    pub opacity: f32,
+    /// Umbral del ruido a partir del cual se abre un hueco estrecho (1 = sin huecos)
+    pub gap_threshold: f32,
+    /// Luz mínima en la cara no iluminada o en sombra
+    pub ambient: f32,
+}
+
+impl Default for Ring {
+    fn default() -> Self {
+        Ring {
+            inner_radius: 1.24,
+            outer_radius: 2.27,
+            inner_color: [140.0, 125.0, 105.0],
+            outer_color: [215.0, 200.0, 170.0],
+            ringlet_freq: 90.0,
+            ringlet_contrast: 0.35,
+            opacity: 0.9,
+            gap_threshold: 0.8,
+            ambient: 0.12,
+        }
+    }
+}
+
+impl Ring {
+    /// Densidad relativa (0 = vacío, 1 = máxima) a `radius` radios del planeta
+    fn density(&self, radius: f32) -> f32 {
+        // Anillos finos: ruido solo en función del radio, a dos escalas
+        let ringlets = noise(radius * self.ringlet_freq, 0.5) * 0.7 + noise(radius * self.ringlet_freq * 3.1, 7.5) * 0.3;
+        let mut density = 1.0 - self.ringlet_contrast * ringlets;
+
+        // Huecos estrechos, como la división de Encke
+        if noise(radius * self.ringlet_freq * 0.2, 3.5) > self.gap_threshold {
+            density *= 0.1;
+        }
+
+        // El primer cuarto es más tenue, como el anillo C de Saturno
+        let t = ((radius - self.inner_radius) / (self.outer_radius - self.inner_radius)).clamp(0.0, 1.0);
+        density * (0.35 + 0.65 * (t * 4.0).min(1.0))
+    }
+}
+
+impl Shader for Ring {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, world_normal, light_dir, view_dir, .. } = *fragment;
+        let radius = Vec3::new(position.x, 0.0, position.z).length();
+        let t = ((radius - self.inner_radius) / (self.outer_radius - self.inner_radius)).clamp(0.0, 1.0);
+        let density = self.density(radius);
+        let base = Vec3::from(self.inner_color).lerp(Vec3::from(self.outer_color), t) * (0.6 + 0.4 * density);
+
+        // Plano fino: se ilumina igual por las dos caras. Las partículas del anillo reflejan
+        // luz aunque el Sol esté rasante, así que la caída con el ángulo es más suave que en
+        // una superficie sólida
+        let lit = world_normal.dot(light_dir).abs().sqrt();
+        let intensity = self.ambient + (1.0 - self.ambient) * lit;
+        let color = (base * intensity).clamp(Vec3::ZERO, Vec3::splat(255.0));
+
+        // Visto de canto la mirada atraviesa más partículas: el espesor óptico crece como
+        // 1 / |cos| del ángulo con la normal
+        let alpha = (self.opacity * density).clamp(0.0, 1.0);
+        let slant = world_normal.dot(view_dir).abs().max(0.05);
+        let alpha = 1.0 - (1.0 - alpha).powf(1.0 / slant);
+
+        Color::RGBA(color.x as u8, color.y as u8, color.z as u8, (alpha * 255.0) as u8)
+    }
+
+    fn is_transparent(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,158 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use std::path::PathBuf;
+use std::sync::Arc;
+use crate::shaders::{Fragment, Shader};
+use crate::texture::Texture;
+use crate::utils::noise;
+
+/// Planeta rocoso tipo Tierra
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RockyPlanet {
+    /// Frecuencia del ruido de los continentes
+    pub land_freq: f32,
+    /// Umbral del ruido a partir del cual hay tierra firme (más alto = más océano)
+    pub land_threshold: f32,
+    /// Colores RGB 0-255 de cada tipo de superficie
+    pub ocean_color: [f32; 3],
+    pub shallow_ocean_color: [f32; 3],
+    pub land_color: [f32; 3],
+    pub desert_color: [f32; 3],
+    pub mountain_color: [f32; 3],
+    /// Latitud normalizada (0.5 = ecuador, 1 = polo) donde empiezan los casquetes de hielo
+    pub ice_cap_threshold: f32,
+    /// Umbral del ruido a partir del cual hay nubes
+    pub cloud_threshold: f32,
+    /// Color que suma la atmósfera en el borde del disco
+    pub atmosphere_color: [f32; 3],
+    /// Mapa de color equirectangular opcional (PNG, relativo al archivo de presets). Sustituye
+    /// a los continentes, océanos y casquetes procedurales; las nubes y la atmósfera se
+    /// siguen dibujando encima.
+    pub texture: Option<PathBuf>,
+    /// El mapa ya cargado (lo rellena `ShaderPresets::load`)
+    #[serde(skip)]
+    pub texture_map: Option<Arc<Texture>>,
+}
+
+impl Default for RockyPlanet {
+    fn default() -> Self {
+        RockyPlanet {
+            land_freq: 5.0,
+            land_threshold: 0.48,
+            ocean_color: [10.0, 50.0, 120.0],
+            shallow_ocean_color: [30.0, 80.0, 150.0],
+            land_color: [34.0, 139.0, 34.0],
+            desert_color: [210.0, 180.0, 140.0],
+            mountain_color: [139.0, 137.0, 137.0],
+            ice_cap_threshold: 0.80,
+            cloud_threshold: 0.6,
+            atmosphere_color: [50.0, 100.0, 200.0],
+            texture: None,
+            texture_map: None,
+        }
+    }
+}
+
+impl RockyPlanet {
+    /// Continentes, océanos y casquetes de hielo procedurales (sin mapa de color)
+    // Las comparaciones de latitud son las del shader original
+    #[allow(clippy::manual_range_contains)]
+    fn procedural_surface(&self, phi: f32, theta: f32, lat: f32, time: f32) -> (f32, f32, f32) {
+        let land_noise = noise(phi * self.land_freq + time * 0.05, theta * self.land_freq);
+        let land_noise2 = noise(phi * self.land_freq * 2.0 - time * 0.03, theta * self.land_freq * 2.0 + 100.0);
+        let is_land = (land_noise * 0.6 + land_noise2 * 0.4) > self.land_threshold;
+        
+        let [ocean_r, ocean_g, ocean_b] = self.ocean_color;
+        let [land_r, land_g, land_b] = self.land_color;
+        
+        let (mut base_r, mut base_g, mut base_b) = if is_land {
+            let terrain_variation = noise(phi * 3.0, theta * 3.0 + 50.0);
+            
+            if lat > 0.75 || lat < 0.25 {
+                (240.0, 240.0, 255.0)
+            } else if terrain_variation > 0.65 {
+                self.mountain_color.into()
+            } else if (lat > 0.35 && lat < 0.42) || (lat > 0.58 && lat < 0.65) {
+                self.desert_color.into()
+            } else {
+                let green_variation = terrain_variation * 20.0;
+                (land_r + green_variation, land_g + green_variation, land_b + green_variation * 0.5)
+            }
+        } else {
+            let depth_noise = noise(phi * 8.0, theta * 8.0 + 200.0);
+            if depth_noise > 0.6 {
+                self.shallow_ocean_color.into()
+            } else {
+                let depth_factor = 0.8 + depth_noise * 0.2;
+                (ocean_r * depth_factor, ocean_g * depth_factor, ocean_b * depth_factor)
+            }
+        };
+        
+        let polar_threshold = self.ice_cap_threshold;
+        let polar_factor = if lat > polar_threshold {
+            ((lat - polar_threshold) / (1.0 - polar_threshold)).powf(0.4)
+        } else if lat < (1.0 - polar_threshold) {
+            ((1.0 - polar_threshold - lat) / (1.0 - polar_threshold)).powf(0.4)
+        } else {
+            0.0
+        };
+        
+        if polar_factor > 0.0 {
+            let snow_white = 255.0;
+            let ice_blue_tint = 0.95;
+            base_r = base_r * (1.0 - polar_factor) + snow_white * polar_factor;
+            base_g = base_g * (1.0 - polar_factor) + snow_white * polar_factor;
+            base_b = base_b * (1.0 - polar_factor) + (snow_white * ice_blue_tint) * polar_factor;
+        }
+        
+        (base_r, base_g, base_b)
+    }
+}
+
+impl Shader for RockyPlanet {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, uv, uv_derivatives, intensity, time, .. } = *fragment;
+        let theta = position.y.atan2((position.x * position.x + position.z * position.z).sqrt());
+        let phi = position.z.atan2(position.x);
+        let lat = (theta / std::f32::consts::PI) + 0.5;
+        
+        let (mut base_r, mut base_g, mut base_b) = match &self.texture_map {
+            Some(texture) => (texture.sample_grad(uv, uv_derivatives.dx, uv_derivatives.dy) * 255.0).into(),
+            None => self.procedural_surface(phi, theta, lat, time),
+        };
+        
+        let cloud_noise1 = noise(phi * 6.0 + time * 0.3, theta * 6.0);
+        let cloud_noise2 = noise(phi * 12.0 - time * 0.2, theta * 12.0 + 300.0);
+        let cloud_combined = cloud_noise1 * 0.6 + cloud_noise2 * 0.4;
+        
+        let cloud_factor = if cloud_combined > self.cloud_threshold {
+            ((cloud_combined - self.cloud_threshold) / (1.0 - self.cloud_threshold)).min(1.0) * 0.7
+        } else {
+            0.0
+        };
+        
+        if cloud_factor > 0.0 {
+            let cloud_white = 240.0;
+            base_r = base_r * (1.0 - cloud_factor) + cloud_white * cloud_factor;
+            base_g = base_g * (1.0 - cloud_factor) + cloud_white * cloud_factor;
+            base_b = base_b * (1.0 - cloud_factor) + cloud_white * cloud_factor;
+        }
+        
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        let atmosphere = edge_factor * edge_factor * 0.25;
+        
+        base_r += atmosphere * self.atmosphere_color[0];
+        base_g += atmosphere * self.atmosphere_color[1];
+        base_b += atmosphere * self.atmosphere_color[2];
+        
+        let enhanced_intensity = intensity * 0.4 + 0.6;
+        
+        let r = (base_r * enhanced_intensity).min(255.0);
+        let g = (base_g * enhanced_intensity).min(255.0);
+        let b = (base_b * enhanced_intensity).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+}
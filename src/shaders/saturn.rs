@@ -0,0 +1,77 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use std::f32::consts::{FRAC_PI_3, PI};
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Saturno - gigante gaseoso de bandas pálidas con el hexágono del polo norte
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Saturn {
+    /// Número de bandas de latitud
+    pub band_freq: f32,
+    /// Colores RGB 0-255 de las zonas claras y los cinturones, de poco contraste
+    pub zone_color: [f32; 3],
+    pub belt_color: [f32; 3],
+    /// Latitud normalizada (0 = polo norte, 1 = polo sur) de los vértices del hexágono
+    pub hexagon_latitude: f32,
+    /// Color de la corriente en chorro que dibuja el hexágono y del vórtice del polo
+    pub hexagon_color: [f32; 3],
+    pub storm_color: [f32; 3],
+    /// Color que suma la atmósfera en el borde del disco
+    pub atmosphere_color: [f32; 3],
+}
+
+impl Default for Saturn {
+    fn default() -> Self {
+        Saturn {
+            band_freq: 9.0,
+            zone_color: [232.0, 210.0, 160.0],
+            belt_color: [205.0, 175.0, 120.0],
+            hexagon_latitude: 0.09,
+            hexagon_color: [140.0, 150.0, 140.0],
+            storm_color: [95.0, 105.0, 120.0],
+            atmosphere_color: [70.0, 65.0, 45.0],
+        }
+    }
+}
+
+impl Shader for Saturn {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, intensity, time, .. } = *fragment;
+        let phi = position.x.atan2(position.z);
+        let theta = (position.y / position.length()).acos();
+        let lat = theta / PI;
+
+        // Bandas anchas y suaves: mezcla continua entre zona y cinturón
+        let band_noise = noise(phi * 2.0 + time * 0.03, lat * 18.0);
+        let band = ((lat * self.band_freq * PI).sin() + band_noise * 0.4) * 0.5 + 0.5;
+        let band = band.clamp(0.0, 1.0);
+        let mut base = Vec3::from(self.belt_color).lerp(Vec3::from(self.zone_color), band);
+
+        let turbulence = noise(phi * 10.0 + time * 0.1, lat * 40.0) - 0.5;
+        base += Vec3::new(18.0, 15.0, 10.0) * turbulence;
+
+        // Hexágono polar: la distancia al polo del borde crece hacia los vértices
+        let hexagon_angle = (phi + time * 0.01).rem_euclid(FRAC_PI_3) - FRAC_PI_3 * 0.5;
+        let hexagon_edge = self.hexagon_latitude * (FRAC_PI_3 * 0.5).cos() / hexagon_angle.cos();
+        let jet = (1.0 - (lat - hexagon_edge).abs() / (self.hexagon_latitude * 0.12)).max(0.0);
+        if lat < hexagon_edge {
+            // Interior del hexágono algo más azulado, con el vórtice oscuro en el centro
+            base = base.lerp(Vec3::from(self.hexagon_color), 0.45);
+            let storm = (1.0 - lat / (self.hexagon_latitude * 0.3)).max(0.0);
+            let swirl = noise(phi * 3.0 - time * 0.2, lat * 120.0);
+            base = base.lerp(Vec3::from(self.storm_color), storm * (0.7 + swirl * 0.3));
+        }
+        base = base.lerp(Vec3::from(self.hexagon_color) * 0.8, jet * 0.8);
+
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        base += Vec3::from(self.atmosphere_color) * (edge_factor * edge_factor * 0.2);
+
+        let enhanced_intensity = intensity * 0.5 + 0.5;
+        let color = (base * enhanced_intensity).clamp(Vec3::ZERO, Vec3::splat(255.0));
+
+        Color::RGB(color.x as u8, color.y as u8, color.z as u8)
+    }
+}
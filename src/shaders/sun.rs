@@ -0,0 +1,86 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Sol: manchas, fulguraciones y corona animadas. Es emisivo: no depende de la luz.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Sun {
+    /// Color de la fotosfera (RGB 0-255)
+    pub base_color: [f32; 3],
+    /// Frecuencia del ruido de las manchas solares
+    pub spot_freq: f32,
+    /// Umbral del ruido a partir del cual hay mancha
+    pub spot_threshold: f32,
+    /// Brillo que conserva una mancha (0 = negra, 1 = sin manchas)
+    pub spot_brightness: f32,
+    /// Umbral del ruido a partir del cual hay fulguración
+    pub flare_threshold: f32,
+    /// Velocidad del pulso de brillo (rad/s)
+    pub pulse_speed: f32,
+    /// Frecuencia del ruido de turbulencia
+    pub turbulence_freq: f32,
+    /// Color que suma la corona en el borde del disco
+    pub corona_color: [f32; 3],
+}
+
+impl Default for Sun {
+    fn default() -> Self {
+        Sun {
+            base_color: [255.0, 180.0, 20.0],
+            spot_freq: 3.0,
+            spot_threshold: 0.65,
+            spot_brightness: 0.6,
+            flare_threshold: 0.7,
+            pulse_speed: 2.0,
+            turbulence_freq: 8.0,
+            corona_color: [100.0, 80.0, 20.0],
+        }
+    }
+}
+
+impl Shader for Sun {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, intensity, time, .. } = *fragment;
+        let radius = (position.x * position.x + position.y * position.y + position.z * position.z).sqrt();
+        let theta = position.y.atan2((position.x * position.x + position.z * position.z).sqrt());
+        let phi = position.z.atan2(position.x);
+        
+        let core_distance = radius.max(0.01);
+        let core_gradient = (1.0 - (core_distance / 2.0).min(1.0)).max(0.0);
+        
+        let base_r = self.base_color[0];
+        let base_g = self.base_color[1] + core_gradient * 50.0;
+        let base_b = self.base_color[2] + core_gradient * 30.0;
+        
+        let spot_freq = self.spot_freq;
+        let spot_noise = noise(phi * spot_freq + time * 0.1, theta * spot_freq);
+        let spot_noise2 = noise(phi * spot_freq * 2.0 - time * 0.15, theta * spot_freq * 2.0);
+        let combined_spots = (spot_noise + spot_noise2 * 0.5) / 1.5;
+        
+        let spot_factor = if combined_spots > self.spot_threshold { self.spot_brightness } else { 1.0 };
+        
+        let flare_noise = noise(phi * 2.0 + time * 0.3, theta * 2.0 + time * 0.2);
+        let flare_factor = if flare_noise > self.flare_threshold { 1.0 + (flare_noise - self.flare_threshold) * 2.0 } else { 1.0 };
+        
+        let pulse = ((time * self.pulse_speed).sin() * 0.5 + 0.5) * 0.15 + 0.85;
+        
+        let turb_noise = noise(phi * self.turbulence_freq + time * 0.5, theta * self.turbulence_freq - time * 0.3);
+        let turb_factor = 0.9 + turb_noise * 0.2;
+        
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        let corona = edge_factor * edge_factor * 0.3;
+        
+        let r = (base_r * spot_factor * flare_factor * pulse * turb_factor * (0.7 + 0.3 * intensity) + corona * self.corona_color[0]).min(255.0);
+        let g = (base_g * spot_factor * flare_factor * pulse * turb_factor * (0.7 + 0.3 * intensity) + corona * self.corona_color[1]).min(255.0);
+        let b = (base_b * spot_factor * pulse * turb_factor * (0.7 + 0.3 * intensity) + corona * self.corona_color[2]).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+
+    fn is_emissive(&self) -> bool {
+        true
+    }
+}
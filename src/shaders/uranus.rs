@@ -0,0 +1,70 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Urano - gigante de hielo azul-verde
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Uranus {
+    /// Color RGB 0-255 de la atmósfera de metano
+    pub base_color: [f32; 3],
+    /// Número de bandas de latitud (muy tenues)
+    pub band_freq: f32,
+    /// Color que suma la atmósfera en el borde del disco
+    pub atmosphere_color: [f32; 3],
+}
+
+impl Default for Uranus {
+    fn default() -> Self {
+        Uranus {
+            base_color: [140.0, 220.0, 220.0],
+            band_freq: 6.0,
+            atmosphere_color: [60.0, 80.0, 80.0],
+        }
+    }
+}
+
+impl Shader for Uranus {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, intensity, time, .. } = *fragment;
+        let phi = position.x.atan2(position.z) + time * 0.08;
+        let theta = (position.y / position.length()).acos();
+        
+        let base_noise = noise(phi * 3.0, theta * 3.0 + time * 0.05);
+        
+        let mut base_r = self.base_color[0] + base_noise * 30.0;
+        let mut base_g = self.base_color[1] + base_noise * 20.0;
+        let mut base_b = self.base_color[2] + base_noise * 25.0;
+        
+        let lat = theta / std::f32::consts::PI;
+        let band_pattern = (lat * self.band_freq).sin();
+        let band_noise = noise(phi * 2.0, lat * 10.0);
+        
+        let band_factor = (band_pattern * 0.5 + 0.5) * (band_noise * 0.5 + 0.5) * 0.15;
+        base_r -= band_factor * 20.0;
+        base_g -= band_factor * 15.0;
+        base_b -= band_factor * 15.0;
+        
+        let atmosphere_noise = noise(phi * 5.0 + time * 0.1, theta * 5.0);
+        base_r += atmosphere_noise * 15.0 - 7.0;
+        base_g += atmosphere_noise * 15.0 - 7.0;
+        base_b += atmosphere_noise * 15.0 - 7.0;
+        
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        let atmosphere = edge_factor * edge_factor * 0.3;
+        
+        base_r += atmosphere * self.atmosphere_color[0];
+        base_g += atmosphere * self.atmosphere_color[1];
+        base_b += atmosphere * self.atmosphere_color[2];
+        
+        let enhanced_intensity = intensity * 0.3 + 0.7;
+        
+        let r = (base_r * enhanced_intensity).min(255.0);
+        let g = (base_g * enhanced_intensity).min(255.0);
+        let b = (base_b * enhanced_intensity).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+}
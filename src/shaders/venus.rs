@@ -0,0 +1,96 @@
+use glam::Vec3;
+use serde::Deserialize;
+use sdl2::pixels::Color;
+use crate::shaders::{Fragment, Shader};
+use crate::utils::noise;
+
+/// Planeta Venus - amarillo/naranja con atmósfera densa
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Venus {
+    /// Velocidad a la que gira la capa de nubes (rad/s)
+    pub cloud_speed: f32,
+    /// Colores RGB 0-255 de las zonas oscuras, intermedias y claras de la atmósfera
+    pub dark_color: [f32; 3],
+    pub mid_color: [f32; 3],
+    pub light_color: [f32; 3],
+    /// Umbral del ruido a partir del cual hay nubes altas
+    pub cloud_threshold: f32,
+    pub cloud_color: [f32; 3],
+    /// Color que suma la atmósfera en el borde del disco
+    pub atmosphere_color: [f32; 3],
+}
+
+impl Default for Venus {
+    fn default() -> Self {
+        Venus {
+            cloud_speed: 0.15,
+            dark_color: [200.0, 140.0, 50.0],
+            mid_color: [230.0, 190.0, 80.0],
+            light_color: [250.0, 220.0, 120.0],
+            cloud_threshold: 0.65,
+            cloud_color: [255.0, 235.0, 150.0],
+            atmosphere_color: [150.0, 120.0, 50.0],
+        }
+    }
+}
+
+impl Shader for Venus {
+    fn shade(&self, fragment: &Fragment) -> Color {
+        let Fragment { position, normal, intensity, time, .. } = *fragment;
+        let phi = position.x.atan2(position.z) + time * self.cloud_speed;
+        let theta = (position.y / position.length()).acos();
+        
+        let base_noise1 = noise(phi * 3.0, theta * 3.0);
+        let base_noise2 = noise(phi * 6.0 + 50.0, theta * 6.0 + 50.0);
+        let base_combined = base_noise1 * 0.6 + base_noise2 * 0.4;
+        
+        let [mut base_r, mut base_g, mut base_b] = if base_combined < 0.35 {
+            self.dark_color
+        } else if base_combined < 0.7 {
+            self.mid_color
+        } else {
+            self.light_color
+        };
+        
+        let band_pattern = (theta * 8.0 + phi * 2.0 + time * 0.3).sin();
+        let band_noise = noise(phi * 4.0 - time * 0.2, theta * 4.0);
+        let band_factor = (band_pattern * 0.5 + 0.5) * (band_noise * 0.5 + 0.5);
+        
+        let band_darken = band_factor * 0.3;
+        base_r -= band_darken * 80.0;
+        base_g -= band_darken * 60.0;
+        base_b -= band_darken * 30.0;
+        
+        let cloud_noise1 = noise(phi * 5.0 + time * 0.4, theta * 5.0);
+        let cloud_noise2 = noise(phi * 10.0 - time * 0.3, theta * 10.0 + 100.0);
+        let cloud_combined = cloud_noise1 * 0.7 + cloud_noise2 * 0.3;
+        
+        let cloud_factor = if cloud_combined > self.cloud_threshold {
+            ((cloud_combined - self.cloud_threshold) / (1.0 - self.cloud_threshold)).min(1.0) * 0.5
+        } else {
+            0.0
+        };
+        
+        if cloud_factor > 0.0 {
+            base_r = base_r * (1.0 - cloud_factor) + self.cloud_color[0] * cloud_factor;
+            base_g = base_g * (1.0 - cloud_factor) + self.cloud_color[1] * cloud_factor;
+            base_b = base_b * (1.0 - cloud_factor) + self.cloud_color[2] * cloud_factor;
+        }
+        
+        let edge_factor = 1.0 - normal.dot(Vec3::new(0.0, 0.0, 1.0)).abs();
+        let atmosphere = edge_factor * edge_factor * 0.4;
+        
+        base_r += atmosphere * self.atmosphere_color[0];
+        base_g += atmosphere * self.atmosphere_color[1];
+        base_b += atmosphere * self.atmosphere_color[2];
+        
+        let enhanced_intensity = intensity * 0.3 + 0.7;
+        
+        let r = (base_r * enhanced_intensity).min(255.0);
+        let g = (base_g * enhanced_intensity).min(255.0);
+        let b = (base_b * enhanced_intensity).min(255.0);
+        
+        Color::RGB(r as u8, g as u8, b as u8)
+    }
+}
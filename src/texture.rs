@@ -0,0 +1,209 @@
+use glam::{Vec2, Vec3};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+use crate::assets::AssetError;
+
+/// Un nivel de la cadena de mipmaps
+struct MipLevel {
+    width: u32,
+    height: u32,
+    texels: Vec<Vec3>,
+}
+
+impl MipLevel {
+    fn texel(&self, x: i32, y: i32) -> Vec3 {
+        let x = x.rem_euclid(self.width as i32) as u32;
+        let y = y.rem_euclid(self.height as i32) as u32;
+        self.texels[(y * self.width + x) as usize]
+    }
+
+    /// Mitad de resolución promediando bloques de 2x2 (los lados impares repiten el borde)
+    fn downsample(&self) -> MipLevel {
+        let width = (self.width / 2).max(1);
+        let height = (self.height / 2).max(1);
+        let mut texels = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let sx = (2 * x).min(self.width - 1) as i32;
+                let sy = (2 * y).min(self.height - 1) as i32;
+                let sx1 = (sx + 1).min(self.width as i32 - 1);
+                let sy1 = (sy + 1).min(self.height as i32 - 1);
+                let sum = self.texel(sx, sy) + self.texel(sx1, sy) + self.texel(sx, sy1) + self.texel(sx1, sy1);
+                texels.push(sum * 0.25);
+            }
+        }
+        MipLevel { width, height, texels }
+    }
+
+    /// Interpolación bilineal entre los cuatro texels más cercanos, repitiendo en ambos ejes
+    fn bilinear(&self, uv: Vec2) -> Vec3 {
+        // v = 0 es la fila inferior de la imagen (convención de OBJ); centros de texel en +0.5
+        let x = uv.x * self.width as f32 - 0.5;
+        let y = (1.0 - uv.y) * self.height as f32 - 0.5;
+        let x0 = x.floor();
+        let y0 = y.floor();
+        let fx = x - x0;
+        let fy = y - y0;
+        let (x0, y0) = (x0 as i32, y0 as i32);
+
+        let top = self.texel(x0, y0).lerp(self.texel(x0 + 1, y0), fx);
+        let bottom = self.texel(x0, y0 + 1).lerp(self.texel(x0 + 1, y0 + 1), fx);
+        top.lerp(bottom, fy)
+    }
+}
+
+/// Imagen en memoria con los colores en [0, 1] y su cadena de mipmaps, lista para
+/// muestrear desde los shaders
+pub struct Texture {
+    levels: Vec<MipLevel>,
+}
+
+impl fmt::Debug for Texture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Texture({}x{}, {} mipmaps)", self.width(), self.height(), self.levels.len())
+    }
+}
+
+impl Texture {
+    /// Crea la textura a partir de bytes RGB (3 por texel, fila por fila) y genera los mipmaps
+    pub fn from_rgb8(width: u32, height: u32, data: &[u8]) -> Self {
+        let texels = data
+            .chunks_exact(3)
+            .map(|p| Vec3::new(p[0] as f32, p[1] as f32, p[2] as f32) / 255.0)
+            .collect();
+
+        let mut levels = vec![MipLevel { width, height, texels }];
+        while let Some(last) = levels.last().filter(|level| level.width > 1 || level.height > 1) {
+            let next = last.downsample();
+            levels.push(next);
+        }
+        Texture { levels }
+    }
+
+    pub fn load_png(path: &Path) -> Result<Self, AssetError> {
+        let file = File::open(path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => AssetError::MissingFile { path: path.to_path_buf() },
+            _ => AssetError::Io { path: path.to_path_buf(), source },
+        })?;
+        let parse_error = |e: png::DecodingError| AssetError::Parse { path: path.to_path_buf(), message: e.to_string() };
+
+        // Cualquier formato de PNG se convierte a 8 bits por canal
+        let mut decoder = png::Decoder::new(BufReader::new(file));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(parse_error)?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).map_err(parse_error)?;
+        let bytes = &buffer[..info.buffer_size()];
+
+        let rgb: Vec<u8> = match info.color_type {
+            png::ColorType::Rgb => bytes.to_vec(),
+            png::ColorType::Rgba => bytes.chunks_exact(4).flat_map(|p| [p[0], p[1], p[2]]).collect(),
+            png::ColorType::Grayscale => bytes.iter().flat_map(|&g| [g, g, g]).collect(),
+            png::ColorType::GrayscaleAlpha => bytes.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0]]).collect(),
+            png::ColorType::Indexed => {
+                return Err(AssetError::Parse {
+                    path: path.to_path_buf(),
+                    message: "PNG indexado sin expandir".to_string(),
+                });
+            },
+        };
+
+        Ok(Texture::from_rgb8(info.width, info.height, &rgb))
+    }
+
+    pub fn width(&self) -> u32 {
+        self.levels[0].width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.levels[0].height
+    }
+
+    /// Muestreo en el nivel de detalle `lod` (0 = resolución completa). Los valores
+    /// fraccionarios mezclan los dos niveles vecinos (filtrado trilineal).
+    pub fn sample_level(&self, uv: Vec2, lod: f32) -> Vec3 {
+        let max_level = (self.levels.len() - 1) as f32;
+        let lod = lod.clamp(0.0, max_level);
+        let base = lod.floor();
+        let fine = self.levels[base as usize].bilinear(uv);
+        if base >= max_level {
+            return fine;
+        }
+        let coarse = self.levels[base as usize + 1].bilinear(uv);
+        fine.lerp(coarse, lod - base)
+    }
+
+    /// Nivel de detalle según cuánto cambian las UV entre píxeles vecinos: cuando un
+    /// píxel cubre más de un texel se baja a un mipmap más pequeño para evitar el aliasing
+    pub fn lod_from_derivatives(&self, uv_dx: Vec2, uv_dy: Vec2) -> f32 {
+        let size = Vec2::new(self.width() as f32, self.height() as f32);
+        let footprint = (uv_dx * size).length().max((uv_dy * size).length());
+        footprint.max(1e-8).log2().max(0.0)
+    }
+
+    /// Muestreo con el mipmap elegido a partir de las derivadas de pantalla de las UV
+    pub fn sample_grad(&self, uv: Vec2, uv_dx: Vec2, uv_dy: Vec2) -> Vec3 {
+        self.sample_level(uv, self.lod_from_derivatives(uv_dx, uv_dy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Textura de 4x4 con el canal rojo igual a x + 4·y (décimas) y el resto a 0
+    fn gradient_4x4() -> Texture {
+        let data: Vec<u8> = (0..16u8).flat_map(|i| [i * 10, 0, 0]).collect();
+        Texture::from_rgb8(4, 4, &data)
+    }
+
+    /// UV del centro del texel (x, y), con y = 0 en la fila superior de la imagen
+    fn texel_center(x: f32, y: f32, size: f32) -> Vec2 {
+        Vec2::new((x + 0.5) / size, 1.0 - (y + 0.5) / size)
+    }
+
+    fn red(value: u8) -> f32 {
+        value as f32 / 255.0
+    }
+
+    #[test]
+    fn mip_level_from_derivatives() {
+        let texture = gradient_4x4();
+        assert_eq!(texture.levels.len(), 3);
+        // Un píxel que avanza 1, 2 o 4 texels usa los niveles 0, 1 y 2
+        assert_eq!(texture.lod_from_derivatives(Vec2::new(0.25, 0.0), Vec2::ZERO), 0.0);
+        assert_eq!(texture.lod_from_derivatives(Vec2::new(0.5, 0.0), Vec2::new(0.0, 0.25)), 1.0);
+        assert_eq!(texture.lod_from_derivatives(Vec2::ZERO, Vec2::new(0.0, 1.0)), 2.0);
+        // Magnificar no baja de la resolución completa
+        assert_eq!(texture.lod_from_derivatives(Vec2::new(0.01, 0.0), Vec2::ZERO), 0.0);
+    }
+
+    #[test]
+    fn bilinear_samples() {
+        let texture = gradient_4x4();
+        let eps = 1e-5;
+
+        // En el centro de un texel se obtiene el texel exacto
+        let sample = texture.sample_level(texel_center(1.0, 2.0, 4.0), 0.0);
+        assert!((sample.x - red(90)).abs() < eps);
+        assert_eq!(sample.y, 0.0);
+
+        // A medio camino entre (1, 2) y (2, 2) la mezcla es la media
+        let sample = texture.sample_level(texel_center(1.5, 2.0, 4.0), 0.0);
+        assert!((sample.x - (red(90) + red(100)) / 2.0).abs() < eps);
+
+        // Entre los cuatro texels (1, 1), (2, 1), (1, 2) y (2, 2)
+        let sample = texture.sample_level(texel_center(1.5, 1.5, 4.0), 0.0);
+        assert!((sample.x - (red(50) + red(60) + red(90) + red(100)) / 4.0).abs() < eps);
+
+        // El nivel 1 promedia bloques de 2x2: el texel (1, 0) es la media de 20, 30, 60 y 70
+        let sample = texture.sample_level(texel_center(1.0, 0.0, 2.0), 1.0);
+        assert!((sample.x - (red(20) + red(30) + red(60) + red(70)) / 4.0).abs() < eps);
+
+        // El último nivel es la media de toda la imagen
+        let mean = (0..16).map(|i| red(i * 10)).sum::<f32>() / 16.0;
+        assert!((texture.sample_level(Vec2::new(0.3, 0.7), 2.0).x - mean).abs() < eps);
+    }
+}
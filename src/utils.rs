@@ -1,5 +1,7 @@
 use glam::Mat4;
 
+// Constante clásica del hash de shaders; se deja tal cual aunque f32 no tenga tanta precisión
+#[allow(clippy::excessive_precision)]
 pub fn hash(x: f32, y: f32) -> f32 {
     let n = (x * 12.9898 + y * 78.233).sin() * 43758.5453;
     n - n.floor()
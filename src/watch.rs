@@ -0,0 +1,50 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Cada cuánto se consulta la fecha de modificación de los archivos vigilados
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Detecta cambios en archivos comparando su fecha de modificación. Se consulta desde el
+/// bucle principal, sin hilos ni APIs de notificación del sistema operativo.
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+    last_poll: Instant,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+impl FileWatcher {
+    pub fn new<P: AsRef<Path>>(paths: impl IntoIterator<Item = P>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let path = path.as_ref().to_path_buf();
+                let stamp = modified(&path);
+                (path, stamp)
+            })
+            .collect();
+        FileWatcher { files, last_poll: Instant::now() }
+    }
+
+    /// Archivos modificados, creados o borrados desde la consulta anterior. Entre consultas
+    /// se espera `POLL_INTERVAL` para no leer el disco en cada cuadro.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return Vec::new();
+        }
+        self.last_poll = Instant::now();
+
+        let mut changed = Vec::new();
+        for (path, stamp) in self.files.iter_mut() {
+            let current = modified(path);
+            if current != *stamp {
+                *stamp = current;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}